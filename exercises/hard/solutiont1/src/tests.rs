@@ -1,10 +1,14 @@
 // src/tests.rs
 mod conjecture;
 
+#[path = "../../time_budget.rs"]
+mod time_budget;
+
 #[cfg(test)]
 mod tests {
     use super::conjecture::goldbach_conjecture;
     use std::time::{Instant, Duration};
+    use super::time_budget::time_budget;
 
     // 定义测试用例和预期结果
     const TEST_CASE: &str = "5777,5993";
@@ -18,7 +22,7 @@ mod tests {
 
         // 时间超0.5s，判定不合格
         let mut total_score = 0.0;
-        if duration <= Duration::from_millis(200) && result == TEST_CASE {
+        if duration <= time_budget(Duration::from_millis(200)) && result == TEST_CASE {
             total_score += 100.0;
         }
 
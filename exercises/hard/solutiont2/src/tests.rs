@@ -1,10 +1,14 @@
 // src/tests.rs
 mod prime_factor;
 
+#[path = "../../time_budget.rs"]
+mod time_budget;
+
 #[cfg(test)]
 mod tests {
     use super::prime_factor::find_max_prime_factor;
     use std::time::{Instant, Duration};
+    use super::time_budget::time_budget;
 
     // 定义测试用例和预期结果
     const TEST_CASES: &[(u128, u128)] = &[
@@ -30,7 +34,7 @@ mod tests {
             let duration = start.elapsed();
 
             // 时间超3s，判定不合格
-            if duration <= Duration::new(3, 0) && result == *expected {
+            if duration <= time_budget(Duration::new(3, 0)) && result == *expected {
                 total_score += 10.0;
             }
         }
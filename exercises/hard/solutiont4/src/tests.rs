@@ -1,10 +1,14 @@
 // src/tests.rs
 mod calc_time;
 
+#[path = "../../time_budget.rs"]
+mod time_budget;
+
 #[cfg(test)]
 mod tests {
     use super::calc_time::time_info;
     use std::time::{Duration, Instant};
+    use super::time_budget::time_budget;
 
     
     const TEST_CASES: &[(&str, &str)] = &[
@@ -39,7 +43,7 @@ mod tests {
             let duration = start.elapsed();
 
             // 时间超0.2s，判定不合格
-            if duration <= Duration::from_millis(200) && result == *expected {
+            if duration <= time_budget(Duration::from_millis(200)) && result == *expected {
                 total_score += 10.0;
             }
         }
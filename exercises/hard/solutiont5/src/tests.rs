@@ -1,10 +1,14 @@
 // src/tests.rs
 mod retirement;
 
+#[path = "../../time_budget.rs"]
+mod time_budget;
+
 #[cfg(test)]
 mod tests {
     use super::retirement::retire_time;
     use std::time::{Instant, Duration};
+    use super::time_budget::time_budget;
 
     // 定义测试用例和预期结果
     const TEST_CASES: &[(&str, &str, &str)] = &[
@@ -30,7 +34,7 @@ mod tests {
             let duration = start.elapsed();
 
             // 时间超0.2s，判定不合格
-            if duration <= Duration::from_millis(200) && result == *expected {
+            if duration <= time_budget(Duration::from_millis(200)) && result == *expected {
                 total_score += 10.0;
             }
         }
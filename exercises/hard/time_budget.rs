@@ -0,0 +1,16 @@
+// 困难难度各 Cargo 项目的测试共用的辅助代码，由 src/tests.rs 通过 #[path] 引入
+use std::time::Duration;
+
+// 与评测程序（src/calibrate.rs 的 MAX_SCALE）相同的缩放系数上限
+const MAX_SCALE: f64 = 20.0;
+
+// 按评测程序测得的机器速度放宽时间预算，未设置 GRADER_TIME_SCALE 时保持原值
+// 与评测程序一样把系数限制在 1 到 MAX_SCALE 之间，手动设置的值也只放宽、不收紧时间预算
+pub fn time_budget(budget: Duration) -> Duration {
+    let scale = std::env::var("GRADER_TIME_SCALE")
+        .ok()
+        .and_then(|value| value.parse::<f64>().ok())
+        .filter(|scale| *scale > 0.0)
+        .map_or(1.0, |scale| scale.clamp(1.0, MAX_SCALE));
+    budget.mul_f64(scale)
+}
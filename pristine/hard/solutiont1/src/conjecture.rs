@@ -0,0 +1,4 @@
+pub fn goldbach_conjecture() -> String {
+    // TODO: implement
+    todo!()
+}
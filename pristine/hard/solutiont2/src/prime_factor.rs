@@ -0,0 +1,4 @@
+pub fn find_max_prime_factor(n: u128) -> u128 {
+    // TODO: implement
+    todo!()
+}
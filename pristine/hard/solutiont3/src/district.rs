@@ -0,0 +1,4 @@
+pub fn process_districts() {
+    // TODO: implement
+    todo!()
+}
@@ -0,0 +1,4 @@
+pub fn time_info(time: &str) -> String {
+    // TODO: implement
+    todo!()
+}
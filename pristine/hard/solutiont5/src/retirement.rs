@@ -0,0 +1,4 @@
+pub fn retire_time(time: &str, tp: &str) -> String {
+    // TODO: implement
+    todo!()
+}
@@ -0,0 +1,4 @@
+pub fn convert_base(num_str: &str, to_base: u32) -> String {
+    // TODO: implement
+    todo!()
+}
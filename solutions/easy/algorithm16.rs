@@ -0,0 +1,99 @@
+/*
+    Rotate Matrix 90 Degrees
+    Given a 2D matrix, rotate it 90 degrees in place. 
+    You need to perform the rotation without using any additional matrix storage.
+
+    You need to implement the function `rotate_matrix_90_degrees(matrix: &mut Vec<Vec<i32>>)`.
+    The function should rotate the input matrix in place.
+
+    Hint: Consider rotating the matrix layer by layer, starting from the outermost layer and working your way inward.
+*/
+
+use std::fmt::{self, Display, Formatter};
+
+pub fn rotate_matrix_90_degrees(matrix: &mut Vec<Vec<i32>>) {
+    let m = matrix.len();      // Number of rows
+    let n = matrix[0].len();   // Number of columns
+    let size = m.max(n);
+
+    // Reversing the rows and then transposing rotates the matrix clockwise.
+    matrix.reverse();
+    // Pad to a square so the transpose can be done by swapping elements in place.
+    matrix.resize_with(size, Vec::new);
+    for row in matrix.iter_mut() {
+        row.resize(size, 0);
+    }
+    for i in 0..size {
+        for j in (i + 1)..size {
+            let (upper, lower) = matrix.split_at_mut(j);
+            std::mem::swap(&mut upper[i][j], &mut lower[0][i]);
+        }
+    }
+    // The rotated matrix has n rows and m columns.
+    matrix.truncate(n);
+    for row in matrix.iter_mut() {
+        row.truncate(m);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rotate_matrix_1() {
+        let mut matrix = vec![
+            vec![1, 2, 3],
+            vec![4, 5, 6],
+            vec![7, 8, 9],
+        ];
+        rotate_matrix_90_degrees(&mut matrix);
+        println!("Rotated matrix: {:?}", matrix);
+        assert_eq!(matrix, vec![
+            vec![7, 4, 1],
+            vec![8, 5, 2],
+            vec![9, 6, 3],
+        ]);
+    }
+
+    #[test]
+    fn test_rotate_matrix_2() {
+        let mut matrix = vec![
+            vec![1, 2],
+            vec![3, 4],
+        ];
+        rotate_matrix_90_degrees(&mut matrix);
+        println!("Rotated matrix: {:?}", matrix);
+        assert_eq!(matrix, vec![
+            vec![3, 1],
+            vec![4, 2],
+        ]);
+    }
+
+    #[test]
+    fn test_rotate_matrix_3() {
+        let mut matrix = vec![
+            vec![1],
+        ];
+        rotate_matrix_90_degrees(&mut matrix);
+        println!("Rotated matrix: {:?}", matrix);
+        assert_eq!(matrix, vec![
+            vec![1],
+        ]);
+    }
+
+    #[test]
+    fn test_rotate_matrix_4() {
+        let mut matrix = vec![
+            vec![1, 2],
+            vec![3, 4],
+            vec![5, 6],
+        ];
+        rotate_matrix_90_degrees(&mut matrix);
+        println!("Rotated matrix: {:?}", matrix);
+        assert_eq!(matrix, vec![
+            vec![5, 3, 1],
+            vec![6, 4, 2],
+        ]);
+    }
+}
@@ -0,0 +1,174 @@
+/*
+	binary_search tree
+	This problem requires you to implement a basic interface for a binary tree
+*/
+
+
+use std::fmt::Debug;
+
+#[derive(Debug)]
+struct TreeNode<T>
+    where
+        T: Ord,
+{
+    value: T,
+    left: Option<Box<TreeNode<T>>>,
+    right: Option<Box<TreeNode<T>>>,
+}
+
+#[derive(Debug)]
+struct BinarySearchTree<T>
+    where
+        T: Ord,
+{
+    root: Option<Box<TreeNode<T>>>,
+}
+
+impl<T> TreeNode<T>
+    where
+        T: Ord,
+{
+    fn new(value: T) -> Self {
+        TreeNode {
+            value,
+            left: None,
+            right: None,
+        }
+    }
+}
+
+impl<T> BinarySearchTree<T>
+    where
+        T: Ord,
+{
+    fn new() -> Self {
+        BinarySearchTree { root: None }
+    }
+
+    // Insert a value into the BST
+    fn insert(&mut self, value: T) {
+        let mut current = &mut self.root;
+        loop {
+            match current {
+                None => {
+                    *current = Some(Box::new(TreeNode::new(value)));
+                    return;
+                }
+                Some(target) => {
+                    if value > target.value {
+                        current = &mut target.right;
+                    } else if value == target.value {
+                        return;
+                    } else {
+                        current = &mut target.left;
+                    }
+                }
+            }
+        }
+    }
+
+    // Search for a value in the BST
+    fn search(&self, value: T) -> bool {
+        let mut current = &self.root;
+        loop {
+            if current.is_none() {
+                return false;
+            }
+            if value == current.as_ref().unwrap().value {
+                return true;
+            } else if value > current.as_ref().unwrap().value {
+                current = &current.as_ref().unwrap().right;
+            } else {
+                current = &current.as_ref().unwrap().left;
+            }
+        }
+    }
+}
+
+impl<T> TreeNode<T>
+    where
+        T: Ord,
+{
+    // Insert a node into the tree
+    fn insert(&mut self, value: T) {
+        if value == self.value {
+            return;
+        }
+        let mut current = if value > self.value {
+            &mut self.right
+        } else {
+            &mut self.left
+        };
+        loop {
+            match current {
+                None => {
+                    *current = Some(Box::new(TreeNode::new(value)));
+                    return;
+                }
+                Some(target) => {
+                    if value > target.value {
+                        current = &mut target.right;
+                    } else if value == target.value {
+                        return;
+                    } else {
+                        current = &mut target.left;
+                    }
+                }
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_and_search() {
+        let mut bst = BinarySearchTree::new();
+
+
+        assert_eq!(bst.search(1), false);
+
+
+        bst.insert(5);
+        bst.insert(3);
+        bst.insert(7);
+        bst.insert(2);
+        bst.insert(4);
+
+
+        assert_eq!(bst.search(5), true);
+        assert_eq!(bst.search(3), true);
+        assert_eq!(bst.search(7), true);
+        assert_eq!(bst.search(2), true);
+        assert_eq!(bst.search(4), true);
+
+
+        assert_eq!(bst.search(1), false);
+        assert_eq!(bst.search(6), false);
+    }
+
+    #[test]
+    fn test_insert_duplicate() {
+        let mut bst = BinarySearchTree::new();
+
+
+        bst.insert(1);
+        bst.insert(1);
+
+
+        assert_eq!(bst.search(1), true);
+
+
+        match bst.root {
+            Some(ref node) => {
+                assert!(node.left.is_none());
+                assert!(node.right.is_none());
+            }
+            None => panic!("Root should not be None after insertion"),
+        }
+    }
+}
+
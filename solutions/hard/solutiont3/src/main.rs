@@ -0,0 +1,7 @@
+// I AM NOT DONE
+
+mod district;
+
+fn main() {
+    let provinces = district::process_districts();
+}
@@ -0,0 +1,7 @@
+//I AM NOT DONE 
+//Calculated according to ISO8061 standard
+
+mod calc_time;
+
+fn main() {
+}
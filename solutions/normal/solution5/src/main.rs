@@ -0,0 +1,7 @@
+mod fibnacci;
+
+fn main() {
+    let num = 20;
+    let sum = fibnacci::odd_fibnacci_sum(num);
+    println!("{sum}");
+}
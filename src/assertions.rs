@@ -0,0 +1,159 @@
+use crate::i18n;
+
+// 逐字符比较的规模上限（两侧长度之积），超过时整行高亮
+const MAX_DIFF_CELLS: usize = 1_000_000;
+
+// libtest 输出中一个未通过的测试
+struct TestFailure {
+    name: String,
+    location: String,
+    // panic 信息（assert_eq! 为 "assertion `left == right` failed" 及附加的说明）
+    message: Vec<String>,
+    left: Option<String>,
+    right: Option<String>,
+    // 测试在 panic 之前打印的内容，通常说明了哪些用例未通过
+    printed: Vec<String>,
+}
+
+// 从 libtest 的输出中解析每个未通过测试的 `---- name stdout ----` 段落
+fn parse_failures(output: &str) -> Vec<TestFailure> {
+    let mut failures: Vec<TestFailure> = Vec::new();
+    // 当前段落是否已经读到 panic 信息之后的调用栈
+    let mut in_backtrace = false;
+    for line in output.lines() {
+        if let Some(name) = line.strip_prefix("---- ").and_then(|rest| rest.strip_suffix(" stdout ----")) {
+            failures.push(TestFailure {
+                name: name.to_string(),
+                location: String::new(),
+                message: Vec::new(),
+                left: None,
+                right: None,
+                printed: Vec::new(),
+            });
+            in_backtrace = false;
+            continue;
+        }
+        // 段落之后是未通过测试的名称列表与汇总
+        if line == "failures:" || line.starts_with("test result:") {
+            in_backtrace = true;
+            continue;
+        }
+        let Some(failure) = failures.last_mut() else {
+            continue;
+        };
+        if in_backtrace || line.starts_with("note: ") {
+            continue;
+        }
+        if line.starts_with("stack backtrace:") {
+            in_backtrace = true;
+        } else if let Some(location) = panic_location(line) {
+            failure.location = location.to_string();
+        } else if failure.location.is_empty() {
+            if !line.trim().is_empty() {
+                failure.printed.push(line.to_string());
+            }
+        } else if let Some(value) = assertion_value(line, "left:") {
+            failure.left = Some(value);
+        } else if let Some(value) = assertion_value(line, "right:") {
+            failure.right = Some(value);
+        } else if !line.trim().is_empty() {
+            failure.message.push(line.to_string());
+        }
+    }
+    failures
+}
+
+// 解析 "thread 'tests::a' (4366) panicked at src/tests.rs:3:54:" 中的位置
+fn panic_location(line: &str) -> Option<&str> {
+    if !line.starts_with("thread '") {
+        return None;
+    }
+    let (_, location) = line.split_once(" panicked at ")?;
+    Some(location.trim_end_matches(':'))
+}
+
+// 解析 "  left: 100.0" 或旧版本的 "  left: `100.0`,"
+fn assertion_value(line: &str, label: &str) -> Option<String> {
+    let value = line.trim_start().strip_prefix(label)?.trim();
+    let value = value.strip_suffix(',').unwrap_or(value);
+    let value = value.strip_prefix('`').and_then(|v| v.strip_suffix('`')).unwrap_or(value);
+    Some(value.to_string())
+}
+
+// 逐字符比较两个值，分别高亮只出现在 left 与只出现在 right 中的字符
+fn highlight(left: &str, right: &str) -> (String, String) {
+    let left: Vec<char> = left.chars().collect();
+    let right: Vec<char> = right.chars().collect();
+    if left.len().saturating_mul(right.len()) > MAX_DIFF_CELLS {
+        let whole = |chars: &[char]| chars.iter().map(|&c| (c, true)).collect::<Vec<_>>();
+        return (render(&whole(&left), "1;31"), render(&whole(&right), "1;32"));
+    }
+    // 最长公共子序列：common[i][j] 为 left[i..] 与 right[j..] 的公共子序列长度
+    let mut common = vec![vec![0usize; right.len() + 1]; left.len() + 1];
+    for i in (0..left.len()).rev() {
+        for j in (0..right.len()).rev() {
+            common[i][j] = if left[i] == right[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    // 标记每个字符是否只出现在一侧
+    let (mut i, mut j) = (0, 0);
+    let (mut left_marks, mut right_marks) = (Vec::new(), Vec::new());
+    while i < left.len() || j < right.len() {
+        if i < left.len() && j < right.len() && left[i] == right[j] {
+            left_marks.push((left[i], false));
+            right_marks.push((right[j], false));
+            i += 1;
+            j += 1;
+        } else if j == right.len() || (i < left.len() && common[i + 1][j] >= common[i][j + 1]) {
+            left_marks.push((left[i], true));
+            i += 1;
+        } else {
+            right_marks.push((right[j], true));
+            j += 1;
+        }
+    }
+    (render(&left_marks, "1;31"), render(&right_marks, "1;32"))
+}
+
+// 把连续的差异字符合并为一段高亮
+fn render(marks: &[(char, bool)], color: &str) -> String {
+    let mut out = String::new();
+    let mut highlighted = false;
+    for &(c, differs) in marks {
+        if differs != highlighted {
+            if differs {
+                out.push_str(&format!("\x1b[{}m", color));
+            } else {
+                out.push_str("\x1b[0m");
+            }
+            highlighted = differs;
+        }
+        out.push(c);
+    }
+    if highlighted {
+        out.push_str("\x1b[0m");
+    }
+    out
+}
+
+// 列出测试输出中每个未通过的测试：位置、打印的内容、panic 信息以及 left 与 right 的差异
+pub fn print_failures(output: &str) {
+    for failure in parse_failures(output) {
+        println!("  {}", i18n::test_failure(&failure.name, &failure.location));
+        for line in &failure.printed {
+            println!("    \x1b[2m|\x1b[0m {}", line);
+        }
+        for line in &failure.message {
+            println!("    {}", line);
+        }
+        if let (Some(left), Some(right)) = (&failure.left, &failure.right) {
+            let (left, right) = highlight(left, right);
+            println!("    {}", i18n::assertion_left(left));
+            println!("    {}", i18n::assertion_right(right));
+        }
+    }
+}
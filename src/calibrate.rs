@@ -0,0 +1,94 @@
+use std::fs;
+use std::io;
+use std::process::Command;
+
+use crate::{i18n, sandbox};
+
+// 传给测试程序的时间缩放系数，测试可用它放宽硬编码的时间预算
+pub const TIME_SCALE_ENV: &str = "GRADER_TIME_SCALE";
+
+// 基准程序在参考机器上（以 debug 模式编译）的耗时，单位为微秒
+const REFERENCE_MICROS: f64 = 80_000.0;
+
+// 取多次运行中的最短耗时，降低偶发干扰的影响
+const CALIBRATION_RUNS: usize = 3;
+
+// 缩放系数的上限，避免机器负载异常时把时间限制放得过宽；exercises/hard/time_budget.rs 中有相同的上限
+const MAX_SCALE: f64 = 20.0;
+
+// 固定的基准程序：筛法求素数并对字符串排序，输出自身的耗时（微秒）
+const BENCHMARK_SOURCE: &str = r#"
+use std::time::Instant;
+
+fn main() {
+    let start = Instant::now();
+    let limit = 2_000_000;
+    let mut sieve = vec![true; limit + 1];
+    let mut checksum = 0u64;
+    for i in 2..=limit {
+        if sieve[i] {
+            checksum = checksum.wrapping_mul(31).wrapping_add(i as u64);
+            let mut j = i * i;
+            while j <= limit {
+                sieve[j] = false;
+                j += i;
+            }
+        }
+    }
+    let mut words: Vec<String> = (0..100_000).map(|i| format!("{:x}", i * 2_654_435_761u64)).collect();
+    words.sort();
+    checksum ^= words.iter().map(|w| w.len() as u64).sum::<u64>();
+    println!("{} {}", start.elapsed().as_micros(), checksum);
+}
+"#;
+
+// 编译并运行基准程序，返回最短耗时（微秒）
+fn run_benchmark() -> io::Result<f64> {
+    let work_dir = sandbox::create_scratch_dir("calibrate")?;
+    let source = work_dir.join("bench.rs");
+    let binary = work_dir.join("bench");
+    let result = fs::write(&source, BENCHMARK_SOURCE).and_then(|_| {
+        let compiled = Command::new("rustc")
+            .args(["--edition=2021", "-Copt-level=0"])
+            .arg(&source)
+            .arg("-o")
+            .arg(&binary)
+            .output()?;
+        if !compiled.status.success() {
+            return Err(io::Error::other(i18n::benchmark_build_failed()));
+        }
+        let mut best = f64::MAX;
+        for _ in 0..CALIBRATION_RUNS {
+            let output = Command::new(&binary).output()?;
+            let micros = String::from_utf8_lossy(&output.stdout)
+                .split_whitespace()
+                .next()
+                .and_then(|value| value.parse::<f64>().ok())
+                .ok_or_else(|| io::Error::other(i18n::benchmark_output_invalid()))?;
+            best = best.min(micros);
+        }
+        Ok(best)
+    });
+    sandbox::remove_scratch_dir(&work_dir);
+    result
+}
+
+// 确定本次评测的时间缩放系数并写入环境变量，之后启动的测试程序都会继承
+// 已设置 GRADER_TIME_SCALE 时直接使用该值；系数限制在 1 到 MAX_SCALE 之间，即只放宽、不收紧时间预算
+pub fn calibrate_time_scale() -> f64 {
+    let configured = std::env::var(TIME_SCALE_ENV).ok().and_then(|value| value.parse::<f64>().ok());
+    let scale = match configured {
+        Some(scale) if scale > 0.0 => scale,
+        _ => match run_benchmark() {
+            Ok(micros) => micros / REFERENCE_MICROS,
+            Err(e) => {
+                eprintln!("{}", i18n::calibration_failed(e));
+                1.0
+            }
+        },
+    };
+    let scale = (scale.clamp(1.0, MAX_SCALE) * 100.0).round() / 100.0;
+    std::env::set_var(TIME_SCALE_ENV, scale.to_string());
+    println!("{}", i18n::time_scale(scale));
+    scale
+}
@@ -0,0 +1,341 @@
+use serde_json::Value;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::config::{FunctionCase, FunctionCases};
+use crate::logs::{self, BuildLog};
+use crate::metrics::{self, Measured, Metrics};
+use crate::{compile, i18n, runtime, sandbox, Exercise};
+
+// 配置中未内联用例时，从习题目录下的该文件读取
+pub const CASES_FILE: &str = "cases.json";
+
+// 测试程序把每个用例的结果写入该环境变量指向的文件
+const REPORT_ENV: &str = "FUNCTION_CASES_REPORT";
+
+const INTEGER_TYPES: &[&str] = &[
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+// 单个用例的运行结果
+enum CaseOutcome {
+    Passed,
+    // 返回值不符，附带实际返回值的 Debug 输出
+    Failed(String),
+    // 被测函数 panic，附带 panic 信息
+    Panicked(String),
+    // 报告中没有该用例的结果，例如测试程序在运行到该用例前就退出了
+    Missing,
+}
+
+fn spec(exercise: &Exercise) -> io::Result<&FunctionCases> {
+    exercise
+        .function_cases
+        .as_ref()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, i18n::function_cases_missing()))
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+// 读取习题的用例：优先使用配置中内联的用例，否则读取习题目录下的 cases.json
+pub fn load_cases(exercise: &Exercise, path: &Path) -> io::Result<Vec<FunctionCase>> {
+    let spec = spec(exercise)?;
+    if !spec.cases.is_empty() {
+        return Ok(spec.cases.clone());
+    }
+    parse_cases(&fs::read_to_string(path.join(CASES_FILE))?)
+}
+
+pub fn parse_cases(content: &str) -> io::Result<Vec<FunctionCase>> {
+    serde_json::from_str(content).map_err(|e| invalid_data(i18n::invalid_cases(e)))
+}
+
+// 把 JSON 值转换为指定类型的 Rust 字面量
+fn literal(ty: &str, value: &Value) -> Result<String, String> {
+    let ty = ty.trim();
+    let mismatch = || i18n::cannot_convert(value, ty);
+    if let Some(inner) = ty.strip_prefix("Vec<").and_then(|rest| rest.strip_suffix('>')) {
+        let items = value.as_array().ok_or_else(mismatch)?;
+        let items: Vec<String> = items.iter().map(|item| literal(inner, item)).collect::<Result<_, _>>()?;
+        return Ok(format!("vec![{}]", items.join(", ")));
+    }
+    if let Some(inner) = ty.strip_prefix("&[").and_then(|rest| rest.strip_suffix(']')) {
+        let items = value.as_array().ok_or_else(mismatch)?;
+        let items: Vec<String> = items.iter().map(|item| literal(inner, item)).collect::<Result<_, _>>()?;
+        return Ok(format!("&[{}]", items.join(", ")));
+    }
+    if let Some(inner) = ty.strip_prefix("Option<").and_then(|rest| rest.strip_suffix('>')) {
+        return match value {
+            Value::Null => Ok("None".to_string()),
+            _ => Ok(format!("Some({})", literal(inner, value)?)),
+        };
+    }
+    match (ty, value) {
+        ("&str", Value::String(s)) => Ok(format!("{:?}", s)),
+        ("String", Value::String(s)) => Ok(format!("{:?}.to_string()", s)),
+        ("char", Value::String(s)) if s.chars().count() == 1 => Ok(format!("{:?}", s.chars().next().unwrap_or_default())),
+        ("bool", Value::Bool(b)) => Ok(b.to_string()),
+        ("f32" | "f64", Value::Number(n)) => n.as_f64().map(|f| format!("{:?}{}", f, ty)).ok_or_else(mismatch),
+        (_, Value::Number(n)) if INTEGER_TYPES.contains(&ty) && (n.is_i64() || n.is_u64()) => Ok(format!("{}{}", n, ty)),
+        _ => Err(mismatch()),
+    }
+}
+
+// 生成测试程序：通过 #[path] 引入被测函数所在的源文件，逐个调用并比较返回值
+fn harness_source(spec: &FunctionCases, source: &Path, cases: &[FunctionCase]) -> io::Result<String> {
+    let is_float = matches!(spec.returns.trim(), "f32" | "f64");
+    let mut body = String::new();
+    for (index, case) in cases.iter().enumerate() {
+        if case.args.len() != spec.params.len() {
+            return Err(invalid_data(i18n::wrong_argument_count(index + 1, case.args.len(), spec.params.len())));
+        }
+        let args: Vec<String> = spec
+            .params
+            .iter()
+            .zip(&case.args)
+            .map(|(ty, value)| literal(ty, value))
+            .collect::<Result<_, _>>()
+            .map_err(invalid_data)?;
+        let expected = literal(&spec.returns, &case.expected).map_err(invalid_data)?;
+        let matches = match spec.tolerance {
+            Some(tolerance) if is_float => format!("(actual - expected).abs() <= {:?}", tolerance),
+            _ => "actual == expected".to_string(),
+        };
+        body.push_str(&format!(
+            r#"
+    let outcome = std::panic::catch_unwind(|| solution::{function}({args}));
+    let line = match outcome {{
+        Ok(actual) => {{
+            let expected: {returns} = {expected};
+            if {matches} {{
+                "ok".to_string()
+            }} else {{
+                failed = true;
+                format!("fail\t{{:?}}", actual)
+            }}
+        }}
+        Err(payload) => {{
+            failed = true;
+            format!("panic\t{{:?}}", panic_message(payload))
+        }}
+    }};
+    writeln!(out, "{index}\t{{}}", line).expect("cannot write report");
+"#,
+            function = spec.function,
+            args = args.join(", "),
+            returns = spec.returns,
+        ));
+    }
+    Ok(format!(
+        r#"#![allow(warnings)]
+
+#[path = {source:?}]
+mod solution;
+
+use std::io::Write;
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {{
+    payload
+        .downcast_ref::<&str>()
+        .map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}}
+
+fn main() {{
+    std::panic::set_hook(Box::new(|_| {{}}));
+    let path = std::env::var_os("{env}").expect("report path not set");
+    let mut out = std::fs::File::create(path).expect("cannot create report");
+    let mut failed = false;
+{body}
+    std::process::exit(if failed {{ 1 }} else {{ 0 }});
+}}
+"#,
+        source = source,
+        env = REPORT_ENV,
+        body = body,
+    ))
+}
+
+// 在 work_dir 中生成并编译测试程序，返回编译过程与测试程序的位置
+pub fn build_harness(
+    exercise: &Exercise,
+    path: &Path,
+    cases: &[FunctionCase],
+    work_dir: &Path,
+    stress: bool,
+    log: Option<&BuildLog>,
+) -> io::Result<(Measured, PathBuf)> {
+    let spec = spec(exercise)?;
+    let source = fs::canonicalize(path.join(&spec.source))?;
+    let harness = work_dir.join("function_cases.rs");
+    fs::write(&harness, harness_source(spec, &source, cases)?)?;
+    let binary = work_dir.join("function_cases");
+    let step = if stress { "stress-compile" } else { "compile" };
+    let compiled = logs::run_and_record(
+        log,
+        step,
+        Command::new("rustc")
+            .args(compile::rustc_args(exercise, stress))
+            .envs(&exercise.env)
+            .arg(&harness)
+            .arg("-o")
+            .arg(&binary),
+    )?;
+    Ok((compiled, binary))
+}
+
+pub fn harness_command(binary: &Path, work_dir: &Path) -> Command {
+    let mut command = Command::new(binary);
+    command.env(REPORT_ENV, work_dir.join("cases.txt"));
+    command
+}
+
+fn read_outcomes(work_dir: &Path, count: usize) -> Vec<CaseOutcome> {
+    let report = fs::read_to_string(work_dir.join("cases.txt")).unwrap_or_default();
+    let mut outcomes: Vec<CaseOutcome> = (0..count).map(|_| CaseOutcome::Missing).collect();
+    for line in report.lines() {
+        let mut fields = line.splitn(3, '\t');
+        let (Some(index), Some(kind)) = (fields.next().and_then(|i| i.parse::<usize>().ok()), fields.next()) else {
+            continue;
+        };
+        let detail = fields.next().unwrap_or_default().to_string();
+        if let Some(outcome) = outcomes.get_mut(index) {
+            *outcome = match kind {
+                "ok" => CaseOutcome::Passed,
+                "fail" => CaseOutcome::Failed(detail),
+                _ => CaseOutcome::Panicked(detail),
+            };
+        }
+    }
+    outcomes
+}
+
+// 列出未通过的用例，返回通过的用例数
+fn report_outcomes(function: &str, cases: &[FunctionCase], outcomes: &[CaseOutcome]) -> usize {
+    let mut passed = 0;
+    for (index, (case, outcome)) in cases.iter().zip(outcomes).enumerate() {
+        let args: Vec<String> = case.args.iter().map(Value::to_string).collect();
+        let call = format!("{}({})", function, args.join(", "));
+        match outcome {
+            CaseOutcome::Passed => passed += 1,
+            CaseOutcome::Failed(actual) => {
+                println!("  {}", i18n::case_failed(index + 1, call, &case.expected, actual));
+            }
+            CaseOutcome::Panicked(message) => {
+                println!("  {}", i18n::case_panicked(index + 1, call, message));
+            }
+            CaseOutcome::Missing => println!("  {}", i18n::case_not_run(index + 1, call)),
+        }
+    }
+    passed
+}
+
+// 报告中每个用例都有结果且全部通过；测试程序提前以退出码 0 结束时不算通过
+fn all_cases_passed(work_dir: &Path, count: usize) -> bool {
+    read_outcomes(work_dir, count).iter().all(|outcome| matches!(outcome, CaseOutcome::Passed))
+}
+
+// 只运行一组用例而不列出细节（用于隐藏用例），返回是否全部通过
+pub fn run_cases(exercise: &Exercise, path: &Path, cases: &[FunctionCase], work_dir: &Path) -> io::Result<bool> {
+    let (compiled, binary) = build_harness(exercise, path, cases, work_dir, false, None)?;
+    if !compiled.status.success() {
+        return Ok(false);
+    }
+    let runtime = runtime::prepare(exercise, path)?;
+    let mut command = harness_command(&binary, work_dir);
+    runtime.apply(&mut command, exercise);
+    let run = metrics::run_measured(&mut command);
+    runtime.finish();
+    Ok(run?.status.success() && all_cases_passed(work_dir, cases.len()))
+}
+
+// 评测 function_cases 习题：生成测试程序逐个运行用例，记录通过的用例数
+pub fn evaluate_function_cases(
+    exercise: &Exercise,
+    path: &Path,
+    stress: bool,
+    repeat: usize,
+    metrics: &mut Metrics,
+    log: Option<&BuildLog>,
+) -> bool {
+    let work_dir = match sandbox::create_scratch_dir(&format!("cases-{}", exercise.name)) {
+        Ok(dir) => dir,
+        Err(e) => {
+            eprintln!("{}", i18n::cases_load_failed(&exercise.name, e));
+            return false;
+        }
+    };
+    let passed = run_harness(exercise, path, &work_dir, stress, repeat, metrics, log);
+    sandbox::remove_scratch_dir(&work_dir);
+    passed
+}
+
+fn run_harness(
+    exercise: &Exercise,
+    path: &Path,
+    work_dir: &Path,
+    stress: bool,
+    repeat: usize,
+    metrics: &mut Metrics,
+    log: Option<&BuildLog>,
+) -> bool {
+    let built = spec(exercise).and_then(|spec| {
+        let cases = load_cases(exercise, path)?;
+        let harness = build_harness(exercise, path, &cases, work_dir, stress, log)?;
+        Ok((spec, cases, harness))
+    });
+    let (spec, cases, (compiled, binary)) = match built {
+        Ok(built) => built,
+        Err(e) => {
+            eprintln!("{}", i18n::cases_load_failed(&exercise.name, e));
+            return false;
+        }
+    };
+    metrics.compile_ms = compiled.elapsed_ms();
+    if !compiled.status.success() {
+        eprintln!("\x1b[31m{}\x1b[0m", i18n::compilation_failed(path.display()));
+        return false;
+    }
+
+    let runtime = match runtime::prepare(exercise, path) {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("{}", i18n::fixtures_copy_failed(&exercise.name, e));
+            return false;
+        }
+    };
+    let test_command = || {
+        let mut command = harness_command(&binary, work_dir);
+        runtime.apply(&mut command, exercise);
+        command
+    };
+    let step = if stress { "stress-test" } else { "test" };
+    let completed = |_: &Measured| all_cases_passed(work_dir, cases.len());
+    let runs = metrics::run_tests_repeatedly(test_command, repeat, completed, metrics, log, step);
+    runtime.finish();
+    let passed_cases = report_outcomes(&spec.function, &cases, &read_outcomes(work_dir, cases.len()));
+    metrics.cases = Some((passed_cases, cases.len()));
+    match runs {
+        Ok(passes) if passes == repeat => {
+            println!("\x1b[32m{}\x1b[0m", i18n::cases_passed(path.display(), passed_cases, cases.len()));
+            true
+        }
+        Ok(passes) if passes > 0 => {
+            println!("\x1b[33m{}\x1b[0m", i18n::test_flaky(path.display(), passes, repeat));
+            false
+        }
+        Ok(_) => {
+            println!("\x1b[31m{}\x1b[0m", i18n::cases_passed(path.display(), passed_cases, cases.len()));
+            false
+        }
+        Err(_) => {
+            eprintln!("{}", i18n::test_run_failed(path.display()));
+            false
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::process::Command;
+
+use crate::Exercise;
+
+// 单文件习题默认使用与评测程序相同的 edition，避免 rustc 回退到 2015
+pub const DEFAULT_EDITION: &str = "2021";
+
+// 压力模式：以 release 优化级别编译，同时保留调试断言与整数溢出检查
+const STRESS_FLAGS: &[&str] = &["-Copt-level=3", "-Cdebug-assertions=on", "-Coverflow-checks=on"];
+
+// 习题配置的额外编译参数（不含 edition）
+fn extra_flags(exercise: &Exercise, stress: bool) -> Vec<String> {
+    let mut flags = Vec::new();
+    if let Some(level) = &exercise.opt_level {
+        flags.push(format!("-Copt-level={}", level));
+    }
+    flags.extend(exercise.rustc_flags.iter().cloned());
+    if stress {
+        flags.extend(STRESS_FLAGS.iter().map(|flag| flag.to_string()));
+    }
+    flags
+}
+
+// 单文件习题传给 rustc 的参数
+pub fn rustc_args(exercise: &Exercise, stress: bool) -> Vec<String> {
+    let edition = exercise.edition.as_deref().unwrap_or(DEFAULT_EDITION);
+    let mut args = vec![format!("--edition={}", edition)];
+    args.extend(extra_flags(exercise, stress));
+    args
+}
+
+// Cargo 项目通过 RUSTFLAGS 传递额外参数，edition 以项目的 Cargo.toml 为准
+pub fn apply_cargo_flags(command: &mut Command, exercise: &Exercise, stress: bool) {
+    let flags = extra_flags(exercise, stress);
+    if !flags.is_empty() {
+        command.env("RUSTFLAGS", flags.join(" "));
+    }
+}
@@ -0,0 +1,252 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+use crate::config::ComplexityCheck;
+use crate::logs::{self, BuildLog};
+use crate::{compile, i18n, runtime, sandbox, source, Exercise};
+
+// 测试程序把每个规模的耗时写入该环境变量指向的文件
+const REPORT_ENV: &str = "COMPLEXITY_PROBE_REPORT";
+
+// 未配置 sizes 时使用的输入规模
+const DEFAULT_SIZES: &[usize] = &[1_000, 2_000, 4_000, 8_000, 16_000, 32_000];
+
+// 依次轮流测量各个规模的轮数，每个规模取所有轮次中的最短耗时，减少机器负载变化对增长趋势的影响
+const ROUNDS: usize = 5;
+
+// 整个计时程序的运行时间上限，超时视为增长过快
+const PROBE_TIMEOUT: Duration = Duration::from_secs(120);
+
+// 超出声明复杂度的增长指数达到该值时判定为不符合，介于两个阈值之间时只给出警告
+// 声明的类别与下一类别在所测规模上的差距更小时（例如 O(n) 与 O(n log n)），以该差距作为判定阈值
+const FAIL_EXCESS: f64 = 0.5;
+const WARN_EXCESS: f64 = 0.15;
+
+// 复杂度类别的名称及其增长函数
+type Class = (&'static str, fn(f64) -> f64);
+
+// 支持的复杂度类别，名称比较时忽略空白与大小写
+const CLASSES: &[Class] = &[
+    ("O(1)", |_| 1.0),
+    ("O(log n)", f64::ln),
+    ("O(n)", |n| n),
+    ("O(n log n)", |n| n * n.ln()),
+    ("O(n^2)", |n| n * n),
+    ("O(n^3)", |n| n * n * n),
+];
+
+fn normalize(class: &str) -> String {
+    class.chars().filter(|c| !c.is_whitespace()).flat_map(char::to_lowercase).collect()
+}
+
+fn find_class(class: &str) -> Option<Class> {
+    CLASSES.iter().copied().find(|(name, _)| normalize(name) == normalize(class))
+}
+
+// 替换习题中的测试模块，改为对每个规模反复生成输入并计时调用，记录单次调用的最短耗时
+fn probe_source(check: &ComplexityCheck, sizes: &[usize]) -> String {
+    let sizes: Vec<String> = sizes.iter().map(usize::to_string).collect();
+    format!(
+        r#"
+#[cfg(test)]
+mod __complexity_probe {{
+    use super::*;
+    use std::io::Write;
+    use std::time::{{Duration, Instant}};
+
+    #[test]
+    fn __complexity_probe() {{
+        let path = std::env::var_os("{env}").expect("report path not set");
+        let mut out = std::fs::File::create(path).expect("cannot create report");
+        let sizes: [usize; {count}] = [{sizes}];
+        let mut best = [Duration::MAX; {count}];
+        for _ in 0..{rounds} {{
+            for (index, &n) in sizes.iter().enumerate() {{
+                let mut total = Duration::ZERO;
+                let mut runs = 0;
+                while runs < 3 || (total < Duration::from_millis({budget}) && runs < 1000) {{
+                    let input = std::hint::black_box({setup});
+                    let start = Instant::now();
+                    let output = std::hint::black_box({{ {call} }});
+                    let elapsed = start.elapsed();
+                    drop(output);
+                    best[index] = best[index].min(elapsed);
+                    total += elapsed;
+                    runs += 1;
+                }}
+            }}
+        }}
+        for (n, best) in sizes.iter().zip(best) {{
+            writeln!(out, "{{}} {{}}", n, best.as_nanos()).expect("cannot write report");
+        }}
+    }}
+}}
+"#,
+        env = REPORT_ENV,
+        count = sizes.len(),
+        rounds = ROUNDS,
+        budget = 200 / ROUNDS,
+        sizes = sizes.join(", "),
+        setup = check.setup,
+        call = check.call,
+    )
+}
+
+// 编译并运行计时程序，返回各规模及对应耗时（纳秒），None 表示超时
+fn measure(
+    exercise: &Exercise,
+    check: &ComplexityCheck,
+    file_path: &Path,
+    work_dir: &Path,
+    log: Option<&BuildLog>,
+) -> io::Result<Option<Vec<(f64, f64)>>> {
+    let mut content = fs::read_to_string(file_path)?;
+    if let Some(range) = source::find_test_module(&content) {
+        content.replace_range(range, "");
+    }
+    let sizes = if check.sizes.is_empty() { DEFAULT_SIZES } else { &check.sizes };
+    content.push_str(&probe_source(check, sizes));
+    let copy = work_dir.join(file_path.file_name().unwrap_or_default());
+    fs::write(&copy, content)?;
+
+    // 默认以优化级别 2 编译，习题配置的 opt_level 会覆盖该设置
+    let binary = copy.with_extension("");
+    let compiled = logs::run_and_record(
+        log,
+        "complexity-compile",
+        Command::new("rustc")
+            .args(["--test", "-Copt-level=2"])
+            .args(compile::rustc_args(exercise, false))
+            .envs(&exercise.env)
+            .arg(&copy)
+            .arg("-o")
+            .arg(&binary),
+    )?;
+    if !compiled.status.success() {
+        return Err(io::Error::other(i18n::timing_build_failed(&compiled.stderr)));
+    }
+
+    let report = work_dir.join("timings.txt");
+    let runtime = runtime::prepare(exercise, file_path)?;
+    let mut command = Command::new(&binary);
+    command
+        .args(["__complexity_probe::__complexity_probe", "--exact", "--nocapture"])
+        .env(REPORT_ENV, &report);
+    runtime.apply(&mut command, exercise);
+    let status = sandbox::run_with_timeout(&mut command, PROBE_TIMEOUT);
+    runtime.finish();
+    let status = status?;
+    match status {
+        None => Ok(None),
+        Some(status) if !status.success() => Err(io::Error::other(i18n::timing_run_failed())),
+        Some(_) => {
+            let timings = fs::read_to_string(&report)?
+                .lines()
+                .filter_map(|line| {
+                    let (n, nanos) = line.split_once(' ')?;
+                    Some((n.parse().ok()?, nanos.parse::<f64>().ok()?.max(1.0)))
+                })
+                .collect();
+            Ok(Some(timings))
+        }
+    }
+}
+
+// 对 ln(t / f(n)) 与 ln(n) 做最小二乘拟合，斜率即实际增长超出 f(n) 的指数
+fn excess_growth(timings: &[(f64, f64)], f: fn(f64) -> f64) -> f64 {
+    let points: Vec<(f64, f64)> = timings.iter().map(|&(n, t)| (n.ln(), (t / f(n)).ln())).collect();
+    let count = points.len() as f64;
+    let mean_x = points.iter().map(|p| p.0).sum::<f64>() / count;
+    let mean_y = points.iter().map(|p| p.1).sum::<f64>() / count;
+    let covariance: f64 = points.iter().map(|p| (p.0 - mean_x) * (p.1 - mean_y)).sum();
+    let variance: f64 = points.iter().map(|p| (p.0 - mean_x).powi(2)).sum();
+    if variance == 0.0 {
+        0.0
+    } else {
+        covariance / variance
+    }
+}
+
+// 按所测的规模计算判定为不符合与给出警告的阈值
+fn thresholds(timings: &[(f64, f64)], declared: &str) -> (f64, f64) {
+    let next = CLASSES.iter().skip_while(|(name, _)| *name != declared).nth(1);
+    let fail = match (next, find_class(declared)) {
+        (Some(&(_, next)), Some((_, f))) => {
+            let growth: Vec<(f64, f64)> = timings.iter().map(|&(n, _)| (n, next(n))).collect();
+            excess_growth(&growth, f).min(FAIL_EXCESS)
+        }
+        _ => FAIL_EXCESS,
+    };
+    (fail, WARN_EXCESS.min(fail / 2.0))
+}
+
+// 检查习题实现的实际耗时增长是否超出配置声明的复杂度
+// 返回 None 表示未配置复杂度检查或习题类型不支持
+pub fn evaluate_complexity(exercise: &Exercise, path: &Path, log: Option<&BuildLog>) -> Option<bool> {
+    let check = exercise.complexity.as_ref()?;
+    if exercise.exercise_type != "single_file" {
+        println!("{}", i18n::complexity_skipped(&exercise.name));
+        return None;
+    }
+    let Some((declared, f)) = find_class(&check.class) else {
+        eprintln!("{}", i18n::unknown_complexity_class(&exercise.name, &check.class));
+        return Some(false);
+    };
+
+    println!("{}", i18n::measuring_complexity(&exercise.name, declared));
+    let work_dir = match sandbox::create_scratch_dir(&format!("complexity-{}", exercise.name)) {
+        Ok(dir) => dir,
+        Err(e) => {
+            eprintln!("{}", i18n::complexity_prepare_failed(&exercise.name, e));
+            return Some(false);
+        }
+    };
+    let timings = measure(exercise, check, path, &work_dir, log);
+    sandbox::remove_scratch_dir(&work_dir);
+
+    let timings = match timings {
+        Ok(Some(timings)) if timings.len() >= 2 => timings,
+        Ok(Some(_)) => {
+            eprintln!("{}", i18n::not_enough_measurements(&exercise.name));
+            return Some(false);
+        }
+        Ok(None) => {
+            println!("\x1b[31m{}\x1b[0m", i18n::complexity_timed_out(&exercise.name, declared));
+            return Some(check.warn_only);
+        }
+        Err(e) => {
+            eprintln!("{}", i18n::measure_failed(&exercise.name, e));
+            return Some(false);
+        }
+    };
+
+    for (n, nanos) in &timings {
+        println!("  n = {:>8}: {:>12.0} ns", n, nanos);
+    }
+    let (fitted, _) = CLASSES
+        .iter()
+        .min_by(|a, b| excess_growth(&timings, a.1).abs().total_cmp(&excess_growth(&timings, b.1).abs()))
+        .copied()
+        .unwrap_or(("unknown", f));
+    let excess = excess_growth(&timings, f);
+    println!("  {}", i18n::closest_fit(fitted, declared, format!("{:.2}", excess)));
+
+    let (fail_excess, warn_excess) = thresholds(&timings, declared);
+    if excess >= fail_excess {
+        if check.warn_only {
+            println!("\x1b[33m{}\x1b[0m", i18n::complexity_exceeds_warning(&exercise.name, declared));
+            return Some(true);
+        }
+        println!("\x1b[31m{}\x1b[0m", i18n::complexity_exceeds(&exercise.name, declared));
+        Some(false)
+    } else {
+        if excess >= warn_excess {
+            println!("\x1b[33m{}\x1b[0m", i18n::complexity_may_exceed(&exercise.name, declared));
+        }
+        println!("\x1b[32m{}\x1b[0m", i18n::complexity_passed(&exercise.name));
+        Some(true)
+    }
+}
@@ -0,0 +1,395 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::i18n;
+
+// 未显式指定配置文件时，按顺序查找以下文件
+pub const DEFAULT_CONFIG_FILES: &[&str] = &[
+    "exercise_config.json",
+    "exercise_config.toml",
+    "exercise_config.yaml",
+    "exercise_config.yml",
+];
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Exercise {
+    pub name: String,
+    pub path: String,
+    #[serde(rename = "type")]
+    pub exercise_type: String,
+    // 展示信息，习题源码开头的注释中没有时使用
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    // 未填写时为所在分类的名称
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub difficulty: Option<String>,
+    // 未填写时使用所在分类的默认分值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub test_hash: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hidden_tests: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub hints: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solution: Option<String>,
+    // 单文件习题的 rustc edition，默认 2021
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub edition: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rustc_flags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opt_level: Option<String>,
+    // 内存检查模式下每个测试允许泄漏的字节数与峰值内存占用，未填写时只报告不限制
+    // 配置了 memory_call 时，峰值上限只作用于被测函数调用期间
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_leaked_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_peak_bytes: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_call: Option<MemoryCall>,
+    // 题目要求的编码规则，由评测程序静态检查
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub constraints: Constraints,
+    // 复杂度检查模式下声明的时间复杂度与输入生成方式
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub complexity: Option<ComplexityCheck>,
+    // function_cases 习题的被测函数与测试用例
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub function_cases: Option<FunctionCases>,
+    // 需要先完成的习题名称
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub prerequisites: Vec<String>,
+    // 编译与运行测试时设置的环境变量
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    // 传给测试程序的参数，Cargo 项目放在 `cargo test --` 之后
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    // 测试程序的工作目录，相对于测试运行目录（只对单文件与 function_cases 习题有效，Cargo 项目配置时加载报错）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<String>,
+    // 测试需要读取的数据文件，相对于习题所在目录，评测时复制到测试运行目录
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fixtures: Vec<String>,
+    // 以下字段在加载后根据所在阶段与分类填充，不写入配置文件
+    #[serde(skip)]
+    pub stage: String,
+    #[serde(skip)]
+    pub category: String,
+}
+
+impl Exercise {
+    pub fn points(&self) -> i32 {
+        self.score.unwrap_or(0)
+    }
+
+    // 单文件习题可省略 .rs 后缀
+    pub fn matches_name(&self, name: &str) -> bool {
+        self.name == name || self.name.strip_suffix(".rs") == Some(name)
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Constraints {
+    // 不允许出现的记号序列，例如 "+"、"vec!"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forbidden_tokens: Vec<String>,
+    // 不允许使用的路径及其下的条目，例如 "std::collections::HashMap"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub forbidden_paths: Vec<String>,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub forbid_unsafe: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ComplexityCheck {
+    // 声明的复杂度类别，例如 "O(n)"、"O(n log n)"
+    pub class: String,
+    // 根据规模 n 生成输入的表达式，结果绑定为 input，不计入耗时
+    pub setup: String,
+    // 使用 input 调用被测函数的表达式
+    pub call: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sizes: Vec<usize>,
+    // 超出声明的复杂度时只警告，不判为失败
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub warn_only: bool,
+}
+
+// 内存检查模式下单独统计的被测函数调用，生成输入的内存不计入峰值
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct MemoryCall {
+    // 根据规模 n 生成输入的表达式，结果绑定为 input
+    pub setup: String,
+    // 使用 input 调用被测函数的表达式
+    pub call: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sizes: Vec<usize>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct FunctionCases {
+    // 被测函数所在的源文件，相对于习题目录
+    pub source: String,
+    // 被测函数在该源文件中的路径，例如 "new_count_distinct"
+    pub function: String,
+    // 参数与返回值的 Rust 类型，用于把用例中的 JSON 值转换为字面量
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<String>,
+    pub returns: String,
+    // 返回值为浮点数时允许的误差
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tolerance: Option<f64>,
+    // 未填写时读取习题目录下的 cases.json
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cases: Vec<FunctionCase>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FunctionCase {
+    pub args: Vec<serde_json::Value>,
+    pub expected: serde_json::Value,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CourseMetadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub course_id: Option<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub stage: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_score: Option<i32>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Category {
+    pub name: String,
+    // 该分类下每道习题的默认分值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub score: Option<i32>,
+    #[serde(default)]
+    pub exercises: Vec<Exercise>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Stage {
+    pub name: String,
+    #[serde(default)]
+    pub categories: Vec<Category>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct ExerciseConfig {
+    #[serde(default)]
+    pub record_hints: bool,
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub course: CourseMetadata,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stages: Vec<Stage>,
+    // 旧版配置格式：固定的 easy / normal / hard 三个数组，加载后转换为单个阶段
+    #[serde(default, skip_serializing)]
+    easy: Vec<Exercise>,
+    #[serde(default, skip_serializing)]
+    normal: Vec<Exercise>,
+    #[serde(default, skip_serializing)]
+    hard: Vec<Exercise>,
+}
+
+fn is_default<T: Default + PartialEq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+#[derive(Clone, Copy)]
+enum ConfigFormat {
+    Json,
+    Toml,
+    Yaml,
+}
+
+fn config_format(file_path: &str) -> io::Result<ConfigFormat> {
+    match Path::new(file_path).extension().and_then(|ext| ext.to_str()) {
+        Some("json") => Ok(ConfigFormat::Json),
+        Some("toml") => Ok(ConfigFormat::Toml),
+        Some("yaml") | Some("yml") => Ok(ConfigFormat::Yaml),
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            i18n::unsupported_config_format(file_path),
+        )),
+    }
+}
+
+pub fn invalid_data(e: impl std::fmt::Display) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, e.to_string())
+}
+
+// 查找默认配置文件
+pub fn default_config_path() -> &'static str {
+    DEFAULT_CONFIG_FILES
+        .iter()
+        .copied()
+        .find(|path| Path::new(path).exists())
+        .unwrap_or(DEFAULT_CONFIG_FILES[0])
+}
+
+pub fn load_exercise_config(file_path: &str) -> io::Result<ExerciseConfig> {
+    let content = fs::read_to_string(file_path)?;
+    let mut config: ExerciseConfig = match config_format(file_path)? {
+        ConfigFormat::Json => serde_json::from_str(&content)?,
+        ConfigFormat::Toml => toml::from_str(&content).map_err(invalid_data)?,
+        ConfigFormat::Yaml => serde_yaml::from_str(&content).map_err(invalid_data)?,
+    };
+    config.migrate_legacy_categories();
+    config.ordered_exercises().map_err(invalid_data)?;
+    config.check_runtime_options().map_err(invalid_data)?;
+    Ok(config)
+}
+
+pub fn save_exercise_config(file_path: &str, config: &ExerciseConfig) -> io::Result<()> {
+    let content = match config_format(file_path)? {
+        ConfigFormat::Json => serde_json::to_string_pretty(config)?,
+        ConfigFormat::Toml => toml::to_string_pretty(config).map_err(invalid_data)?,
+        ConfigFormat::Yaml => serde_yaml::to_string(config).map_err(invalid_data)?,
+    };
+    let mut file = File::create(file_path)?;
+    write!(file, "{}", content.trim_end())?;
+    writeln!(file)?;
+    Ok(())
+}
+
+impl ExerciseConfig {
+    // 将旧版的三个数组转换为一个阶段下的三个分类
+    fn migrate_legacy_categories(&mut self) {
+        let legacy = [
+            ("easy", std::mem::take(&mut self.easy)),
+            ("normal", std::mem::take(&mut self.normal)),
+            ("hard", std::mem::take(&mut self.hard)),
+        ];
+        if legacy.iter().all(|(_, exercises)| exercises.is_empty()) {
+            return;
+        }
+        let categories = legacy
+            .into_iter()
+            .map(|(name, exercises)| Category {
+                name: name.to_string(),
+                score: None,
+                exercises,
+            })
+            .collect();
+        let name = self.course.stage.clone().unwrap_or_else(|| "default".to_string());
+        self.stages.push(Stage { name, categories });
+    }
+
+    // 按依赖顺序列出习题，stage 为 None 时包含全部阶段
+    // 返回的习题已填充所在阶段、分类以及生效的分值
+    pub fn exercises(&self, stage: Option<&str>) -> Vec<Exercise> {
+        let mut exercises = self.ordered_exercises().unwrap_or_else(|_| self.configured_exercises());
+        exercises.retain(|exercise| stage.is_none_or(|name| exercise.stage == name));
+        exercises
+    }
+
+    // 按配置文件中的顺序列出全部习题
+    fn configured_exercises(&self) -> Vec<Exercise> {
+        let mut exercises = Vec::new();
+        for s in &self.stages {
+            for category in &s.categories {
+                for exercise in &category.exercises {
+                    let mut exercise = exercise.clone();
+                    exercise.score = exercise.score.or(category.score);
+                    exercise.stage = s.name.clone();
+                    exercise.category = category.name.clone();
+                    exercises.push(exercise);
+                }
+            }
+        }
+        exercises
+    }
+
+    // Cargo 项目的测试由 cargo 在项目根目录运行，无法指定 working_dir
+    fn check_runtime_options(&self) -> Result<(), String> {
+        match self.configured_exercises().iter().find(|e| e.exercise_type == "cargo_project" && e.working_dir.is_some()) {
+            Some(exercise) => Err(i18n::working_dir_unsupported(&exercise.name)),
+            None => Ok(()),
+        }
+    }
+
+    // 在保持配置顺序的前提下，把先修习题排在依赖它的习题之前
+    // 先修习题不存在或存在循环依赖时返回错误
+    fn ordered_exercises(&self) -> Result<Vec<Exercise>, String> {
+        let exercises = self.configured_exercises();
+        let mut prerequisites = Vec::new();
+        for exercise in &exercises {
+            let mut indices = Vec::new();
+            for name in &exercise.prerequisites {
+                let index = exercises
+                    .iter()
+                    .position(|other| other.matches_name(name))
+                    .ok_or_else(|| i18n::unknown_prerequisite(name, &exercise.name))?;
+                indices.push(index);
+            }
+            prerequisites.push(indices);
+        }
+
+        let mut done = vec![false; exercises.len()];
+        let mut order = Vec::new();
+        while order.len() < exercises.len() {
+            let next = (0..exercises.len())
+                .find(|&i| !done[i] && prerequisites[i].iter().all(|&p| done[p]));
+            match next {
+                Some(i) => {
+                    done[i] = true;
+                    order.push(exercises[i].clone());
+                }
+                None => {
+                    // 沿着未完成的先修关系走下去，必然回到走过的习题，由此得到一个环
+                    let mut path = vec![(0..exercises.len()).find(|&i| !done[i]).unwrap_or_default()];
+                    loop {
+                        let current = path[path.len() - 1];
+                        let next = prerequisites[current].iter().copied().find(|&p| !done[p]).unwrap_or(current);
+                        if let Some(start) = path.iter().position(|&i| i == next) {
+                            let cycle: Vec<&str> = path[start..]
+                                .iter()
+                                .chain(std::iter::once(&next))
+                                .map(|&i| exercises[i].name.as_str())
+                                .collect();
+                            return Err(i18n::dependency_cycle(cycle.join(" -> ")));
+                        }
+                        path.push(next);
+                    }
+                }
+            }
+        }
+        Ok(order)
+    }
+
+    pub fn exercises_mut(&mut self) -> impl Iterator<Item = &mut Exercise> {
+        self.stages
+            .iter_mut()
+            .flat_map(|stage| stage.categories.iter_mut())
+            .flat_map(|category| category.exercises.iter_mut())
+    }
+
+    // 查找指定阶段（默认为第一个阶段）中的分类
+    pub fn category_mut(&mut self, stage: Option<&str>, category: &str) -> Option<&mut Category> {
+        let stage = match stage {
+            Some(name) => self.stages.iter_mut().find(|s| s.name == name)?,
+            None => self.stages.first_mut()?,
+        };
+        stage.categories.iter_mut().find(|c| c.name == category)
+    }
+
+    // 按名称查找习题，单文件习题可省略 .rs 后缀
+    pub fn find_exercise(&self, name: &str) -> Option<Exercise> {
+        self.exercises(None).into_iter().find(|exercise| exercise.matches_name(name))
+    }
+}
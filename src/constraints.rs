@@ -0,0 +1,317 @@
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::{i18n, source, Exercise};
+
+// 多字符运算符，按长度从长到短匹配
+const PUNCTUATION: &[&str] = &[
+    "<<=", ">>=", "...", "..=", "::", "->", "=>", "==", "!=", "<=", ">=", "&&", "||", "+=", "-=", "*=", "/=", "%=",
+    "^=", "&=", "|=", "<<", ">>", "..",
+];
+
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+}
+
+// 通过 use 引入的名称；name 为 None 表示通配符导入
+struct Import {
+    path: Vec<String>,
+    name: Option<String>,
+    line: usize,
+}
+
+pub struct Violation {
+    file: PathBuf,
+    line: usize,
+    message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.file.display(), self.line, self.message)
+    }
+}
+
+fn is_ident_start(b: u8) -> bool {
+    b.is_ascii_alphabetic() || b == b'_' || b >= 0x80
+}
+
+fn is_ident_continue(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_' || b >= 0x80
+}
+
+// 把源码中 is_code 标记为代码的部分拆分为标识符、数字与运算符
+fn tokenize<'a>(source: &'a str, is_code: &[bool]) -> Vec<Token<'a>> {
+    let bytes = source.as_bytes();
+    let end = bytes.len();
+    let mut tokens = Vec::new();
+    let mut line = 1;
+    let mut i = 0;
+    while i < end {
+        if !is_code[i] || bytes[i].is_ascii_whitespace() {
+            line += usize::from(bytes[i] == b'\n');
+            i += 1;
+            continue;
+        }
+        let len = if is_ident_start(bytes[i]) || bytes[i].is_ascii_digit() {
+            bytes[i..end].iter().take_while(|&&b| is_ident_continue(b)).count()
+        } else {
+            PUNCTUATION
+                .iter()
+                .find(|op| bytes[i..end].starts_with(op.as_bytes()))
+                .map_or(1, |op| op.len())
+        };
+        tokens.push(Token {
+            text: &source[i..i + len],
+            line,
+        });
+        i += len;
+    }
+    tokens
+}
+
+fn is_ident(text: &str) -> bool {
+    text.bytes().next().is_some_and(is_ident_start)
+}
+
+// 展开 use 声明中的路径树，例如 `std::collections::{self, HashMap as Map}`
+fn parse_use_tree(tokens: &[Token], pos: &mut usize, prefix: &[String], imports: &mut Vec<Import>) {
+    let mut path = prefix.to_vec();
+    while let Some(token) = tokens.get(*pos) {
+        match token.text {
+            "::" => *pos += 1,
+            "*" => {
+                *pos += 1;
+                imports.push(Import { path, name: None, line: token.line });
+                return;
+            }
+            "{" => {
+                *pos += 1;
+                while tokens.get(*pos).is_some_and(|t| t.text != "}" && t.text != ";") {
+                    let before = *pos;
+                    parse_use_tree(tokens, pos, &path, imports);
+                    if tokens.get(*pos).is_some_and(|t| t.text == ",") || *pos == before {
+                        *pos += 1;
+                    }
+                }
+                *pos += 1;
+                return;
+            }
+            text if is_ident(text) => {
+                *pos += 1;
+                if text != "self" {
+                    path.push(text.to_string());
+                }
+                if tokens.get(*pos).is_some_and(|t| t.text == "::") {
+                    continue;
+                }
+                let mut name = path.last().cloned();
+                if tokens.get(*pos).is_some_and(|t| t.text == "as") {
+                    name = tokens.get(*pos + 1).map(|t| t.text.to_string());
+                    *pos += 2;
+                }
+                imports.push(Import { path, name, line: token.line });
+                return;
+            }
+            _ => return,
+        }
+    }
+}
+
+fn collect_imports(tokens: &[Token]) -> Vec<Import> {
+    let mut imports = Vec::new();
+    for (index, token) in tokens.iter().enumerate() {
+        if token.text == "use" {
+            parse_use_tree(tokens, &mut (index + 1), &[], &mut imports);
+        }
+    }
+    imports
+}
+
+// 依次取出代码中形如 `a::b::c` 的路径及其所在行
+fn path_expressions<'a>(tokens: &[Token<'a>]) -> Vec<(Vec<&'a str>, usize)> {
+    let mut paths = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        // `::std::x` 这样以 `::` 开头的路径同样从第一个标识符算起
+        let follows_segment = i >= 2
+            && tokens[i - 1].text == "::"
+            && (is_ident(tokens[i - 2].text) || tokens[i - 2].text == ">");
+        let starts_path = is_ident(tokens[i].text) && !follows_segment;
+        if !starts_path {
+            i += 1;
+            continue;
+        }
+        let mut segments = vec![tokens[i].text];
+        let mut j = i + 1;
+        while j + 1 < tokens.len() && tokens[j].text == "::" && is_ident(tokens[j + 1].text) {
+            segments.push(tokens[j + 1].text);
+            j += 2;
+        }
+        paths.push((segments, tokens[i].line));
+        i = j;
+    }
+    paths
+}
+
+// 路径在经过 use 导入的别名展开后，是否指向被禁止的路径或其下的条目
+fn refers_to(segments: &[&str], imports: &[Import], forbidden: &[&str]) -> bool {
+    let starts_with = |full: Vec<&str>| full.len() >= forbidden.len() && full[..forbidden.len()] == *forbidden;
+    if starts_with(segments.to_vec()) {
+        return true;
+    }
+    imports.iter().any(|import| {
+        let mut full: Vec<&str> = import.path.iter().map(String::as_str).collect();
+        match &import.name {
+            Some(name) if name == segments[0] => full.extend(&segments[1..]),
+            Some(_) => return false,
+            None => full.extend(segments),
+        }
+        starts_with(full)
+    })
+}
+
+// 测试依赖的断言宏，学生代码中同名的宏会让未改动的测试模块中的断言失效
+fn is_test_macro(name: &str) -> bool {
+    name.starts_with("assert") || name.starts_with("debug_assert") || name == "panic"
+}
+
+// 宏定义或导入的名称，跳过原始标识符的 `r#` 前缀
+fn item_name<'a, 'b>(tokens: &'b [Token<'a>]) -> Option<&'b Token<'a>> {
+    match tokens {
+        [r, hash, name, ..] if r.text == "r" && hash.text == "#" => Some(name),
+        [name, ..] => Some(name),
+        [] => None,
+    }
+}
+
+// 检查一个源文件，返回违反规则的位置
+fn check_source(exercise: &Exercise, file: &Path, source: &str) -> Vec<Violation> {
+    let rules = &exercise.constraints;
+    let tokens = tokenize(source, &source::student_code_mask(source));
+    let mut violations = Vec::new();
+    let mut report = |line: usize, message: String| {
+        let duplicate = violations
+            .iter()
+            .any(|v: &Violation| v.line == line && v.message == message);
+        if !duplicate {
+            violations.push(Violation {
+                file: file.to_path_buf(),
+                line,
+                message,
+            });
+        }
+    };
+
+    let imports = collect_imports(&tokens);
+    for (index, token) in tokens.iter().enumerate() {
+        if token.text != "macro_rules" || tokens.get(index + 1).is_none_or(|t| t.text != "!") {
+            continue;
+        }
+        if let Some(name) = item_name(&tokens[index + 2..]).filter(|name| is_test_macro(name.text)) {
+            report(name.line, i18n::test_macro_redefined(name.text));
+        }
+    }
+    for import in &imports {
+        let from_std = import.path.first().is_some_and(|root| root == "std" || root == "core");
+        if let Some(name) = import.name.as_deref().filter(|name| is_test_macro(name) && !from_std) {
+            report(import.line, i18n::test_macro_redefined(name));
+        }
+    }
+
+    let mut forbidden_tokens: Vec<&str> = rules.forbidden_tokens.iter().map(String::as_str).collect();
+    if rules.forbid_unsafe {
+        forbidden_tokens.push("unsafe");
+    }
+    for rule in forbidden_tokens {
+        let pattern: Vec<&str> = tokenize(rule, &source::code_mask(rule)).iter().map(|t| t.text).collect();
+        if pattern.is_empty() {
+            continue;
+        }
+        for window in tokens.windows(pattern.len()) {
+            if window.iter().map(|t| t.text).eq(pattern.iter().copied()) {
+                report(window[0].line, i18n::not_allowed(rule));
+            }
+        }
+    }
+
+    let paths = path_expressions(&tokens);
+    for rule in &rules.forbidden_paths {
+        let forbidden: Vec<&str> = rule.trim_start_matches("::").split("::").collect();
+        for (segments, line) in &paths {
+            if refers_to(segments, &imports, &forbidden) {
+                report(*line, i18n::not_allowed(rule));
+            }
+        }
+    }
+    violations
+}
+
+// 需要检查的学生代码文件，测试代码不参与检查
+fn student_files(exercise: &Exercise, path: &Path) -> io::Result<Vec<PathBuf>> {
+    if exercise.exercise_type == "single_file" {
+        return Ok(vec![path.to_path_buf()]);
+    }
+    let mut files = Vec::new();
+    for entry in fs::read_dir(path.join("src"))? {
+        let file = entry?.path();
+        if file.extension().is_some_and(|ext| ext == "rs") && file.file_name().is_some_and(|name| name != "tests.rs") {
+            files.push(file);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+// 按习题配置的规则检查学生代码，返回所有违规位置
+// 单文件与 Cargo 项目即使没有配置规则，也会检查断言宏是否被重新定义
+pub fn check_constraints(exercise: &Exercise, path: &Path) -> io::Result<Vec<Violation>> {
+    let mut violations = Vec::new();
+    if exercise.constraints == Default::default() && exercise.exercise_type == "function_cases" {
+        return Ok(violations);
+    }
+    for file in student_files(exercise, path)? {
+        let source = fs::read_to_string(&file)?;
+        let mut found = check_source(exercise, &file, &source);
+        found.sort_by_key(|violation| violation.line);
+        violations.extend(found);
+    }
+    Ok(violations)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TESTS: &str = "#[cfg(test)]\nmod tests {\n    #[test]\n    fn t() {\n        assert_eq!(super::f(), 1);\n    }\n}\n";
+
+    fn violations(source: &str) -> Vec<String> {
+        let source = format!("{}{}", source, TESTS);
+        check_source(&Exercise::default(), Path::new("a.rs"), &source)
+            .iter()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    #[test]
+    fn redefined_assert_macros_are_rejected() {
+        assert_eq!(violations("macro_rules! assert_eq { ($($t:tt)*) => {}; }\nfn f() -> i32 { 0 }\n").len(), 1);
+        assert_eq!(violations("macro_rules! r#panic { () => {}; }\nfn f() -> i32 { 0 }\n").len(), 1);
+        assert_eq!(violations("macro_rules! nop { ($($t:tt)*) => {}; }\nuse nop as debug_assert;\nfn f() -> i32 { 0 }\n").len(), 1);
+    }
+
+    #[test]
+    fn other_macros_and_std_imports_are_allowed() {
+        assert!(violations("macro_rules! square { ($x:expr) => { $x * $x }; }\nfn f() -> i32 { square!(1) }\n").is_empty());
+        assert!(violations("use std::assert_eq;\nfn f() -> i32 { 1 }\n").is_empty());
+    }
+
+    #[test]
+    fn macros_inside_the_test_module_are_not_student_code() {
+        let source = "fn f() -> i32 { 1 }\n#[cfg(test)]\nmod tests {\n    macro_rules! assert_close { () => {}; }\n}\n";
+        assert!(check_source(&Exercise::default(), Path::new("a.rs"), source).is_empty());
+    }
+}
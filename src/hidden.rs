@@ -0,0 +1,92 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::{cases, compile, evaluate_single_file, i18n, runtime, sandbox, source, Exercise, Metrics};
+
+// 隐藏测试根目录，由评测环境通过环境变量提供，不随习题仓库分发
+const HIDDEN_TESTS_DIR_ENV: &str = "HIDDEN_TESTS_DIR";
+
+fn hidden_tests_root() -> Option<PathBuf> {
+    std::env::var_os(HIDDEN_TESTS_DIR_ENV).map(PathBuf::from)
+}
+
+// 针对 path 处的习题代码运行隐藏测试
+// 返回 None 表示该习题未配置隐藏测试或评测环境未提供隐藏测试目录
+pub fn evaluate_hidden_tests(exercise: &Exercise, path: &Path) -> Option<bool> {
+    let relative = exercise.hidden_tests.as_ref()?;
+    let Some(root) = hidden_tests_root() else {
+        println!("{}", i18n::hidden_skipped(&exercise.name, HIDDEN_TESTS_DIR_ENV));
+        return None;
+    };
+
+    let hidden_source = match fs::read_to_string(root.join(relative)) {
+        Ok(content) => content,
+        Err(e) => {
+            eprintln!("{}", i18n::hidden_read_failed(&exercise.name, e));
+            return Some(false);
+        }
+    };
+
+    let work_dir = match sandbox::create_scratch_dir(&format!("hidden-{}", exercise.name)) {
+        Ok(dir) => dir,
+        Err(e) => {
+            eprintln!("{}", i18n::hidden_prepare_failed(&exercise.name, e));
+            return Some(false);
+        }
+    };
+
+    let passed = match exercise.exercise_type.as_str() {
+        "single_file" => run_single_file(exercise, path, &hidden_source, &work_dir),
+        "cargo_project" => run_cargo_project(exercise, path, &hidden_source, &work_dir),
+        // 隐藏测试文件为与 cases.json 格式相同的用例列表
+        "function_cases" => {
+            cases::parse_cases(&hidden_source).and_then(|hidden| cases::run_cases(exercise, path, &hidden, &work_dir))
+        }
+        _ => Ok(false),
+    };
+    sandbox::remove_scratch_dir(&work_dir);
+
+    let passed = passed.unwrap_or_else(|e| {
+        eprintln!("{}", i18n::hidden_run_failed(&exercise.name, e));
+        false
+    });
+    if passed {
+        println!("\x1b[32m{}\x1b[0m", i18n::hidden_passed(&exercise.name));
+    } else {
+        println!("\x1b[31m{}\x1b[0m", i18n::hidden_failed(&exercise.name));
+    }
+    Some(passed)
+}
+
+// 单文件习题：用隐藏测试模块替换可见测试模块后编译运行
+fn run_single_file(exercise: &Exercise, file_path: &Path, hidden_source: &str, work_dir: &Path) -> io::Result<bool> {
+    let mut content = fs::read_to_string(file_path)?;
+    if let Some(range) = source::find_test_module(&content) {
+        content.replace_range(range, "");
+    }
+    content.push_str(&format!("\n#[cfg(test)]\nmod hidden_tests {{\n{}\n}}\n", hidden_source));
+
+    let file_name = file_path.file_name().unwrap_or_default();
+    let combined = work_dir.join(file_name);
+    fs::write(&combined, content)?;
+    Ok(evaluate_single_file(exercise, &combined, false, 1, &mut Metrics::default(), None))
+}
+
+// Cargo 项目：在项目副本中加入名为 hidden_tests 的测试目标并单独运行
+fn run_cargo_project(exercise: &Exercise, proj_path: &Path, hidden_source: &str, work_dir: &Path) -> io::Result<bool> {
+    let copy = work_dir.join("project");
+    sandbox::copy_dir(proj_path, &copy)?;
+    fs::write(copy.join("src").join("hidden_tests.rs"), hidden_source)?;
+
+    let mut manifest = OpenOptions::new().append(true).open(copy.join("Cargo.toml"))?;
+    writeln!(manifest, "\n[[test]]\nname = \"hidden_tests\"\npath = \"src/hidden_tests.rs\"")?;
+
+    runtime::prepare(exercise, &copy)?.finish();
+
+    let mut cargo = Command::new("cargo");
+    cargo.args(["test", "--test", "hidden_tests", "--"]).args(&exercise.args).current_dir(&copy).envs(&exercise.env);
+    compile::apply_cargo_flags(&mut cargo, exercise, false);
+    Ok(cargo.output()?.status.success())
+}
@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+
+use crate::{i18n, Exercise};
+
+// 本地记录每道习题已查看的提示数量
+const HINT_STATE_FILE: &str = ".grader/hints.json";
+
+fn load_hint_state() -> BTreeMap<String, usize> {
+    File::open(HINT_STATE_FILE)
+        .ok()
+        .and_then(|file| serde_json::from_reader(file).ok())
+        .unwrap_or_default()
+}
+
+fn save_hint_state(state: &BTreeMap<String, usize>) -> io::Result<()> {
+    if let Some(parent) = Path::new(HINT_STATE_FILE).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = File::create(HINT_STATE_FILE)?;
+    serde_json::to_writer_pretty(&mut file, state)?;
+    writeln!(file)?;
+    Ok(())
+}
+
+pub fn hints_used(exercise_name: &str) -> usize {
+    load_hint_state().get(exercise_name).copied().unwrap_or(0)
+}
+
+// 每调用一次多揭示一条提示，并打印目前已揭示的全部提示
+pub fn reveal_next_hint(exercise: &Exercise) -> io::Result<()> {
+    if exercise.hints.is_empty() {
+        println!("{}", i18n::no_hints(&exercise.name));
+        return Ok(());
+    }
+
+    let mut state = load_hint_state();
+    let revealed = state.entry(exercise.name.clone()).or_insert(0);
+    let all_revealed = *revealed >= exercise.hints.len();
+    if !all_revealed {
+        *revealed += 1;
+    }
+    let revealed = *revealed;
+    save_hint_state(&state)?;
+
+    for (i, hint) in exercise.hints.iter().take(revealed).enumerate() {
+        println!("{}", i18n::hint(i + 1, exercise.hints.len(), hint));
+    }
+    if all_revealed {
+        println!("{}", i18n::all_hints_revealed(&exercise.name));
+    }
+    Ok(())
+}
@@ -0,0 +1,836 @@
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+// 评测程序输出文字的语言，报告中的字段名不受影响
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    En,
+    Zh,
+}
+
+impl FromStr for Lang {
+    type Err = String;
+
+    // 接受 `zh`、`zh-CN`、`zh_CN.UTF-8`、`en`、`en_US` 等写法
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let code = value.split(['-', '_', '.']).next().unwrap_or_default().to_lowercase();
+        match code.as_str() {
+            "zh" => Ok(Lang::Zh),
+            "en" | "c" | "posix" => Ok(Lang::En),
+            _ => Err(format!("unsupported language: {}", value)),
+        }
+    }
+}
+
+static LANG: OnceLock<Lang> = OnceLock::new();
+
+// 根据系统区域设置选择语言，无法识别时使用英文
+fn detect_language() -> Lang {
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .filter_map(|name| std::env::var(name).ok())
+        .find(|value| !value.is_empty())
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(Lang::En)
+}
+
+// 指定输出语言，须在输出任何文字之前调用
+pub fn set_language(lang: Lang) {
+    let _ = LANG.set(lang);
+}
+
+pub fn language() -> Lang {
+    *LANG.get_or_init(detect_language)
+}
+
+// 为每条消息生成一个同名函数，按当前语言格式化对应的文字
+macro_rules! messages {
+    ($($name:ident($($arg:ident),*) { en: $en:literal, zh: $zh:literal, })*) => {
+        $(
+            pub fn $name($($arg: impl std::fmt::Display),*) -> String {
+                match language() {
+                    Lang::En => format!($en),
+                    Lang::Zh => format!($zh),
+                }
+            }
+        )*
+    };
+}
+
+messages! {
+    // 命令行
+    missing_command() {
+        en: "Please provide a command: 'watch', 'all', 'next', 'hash', 'hint <name>', 'logs <name>', 'new <name>', 'reset <name>', 'verify-solutions', 'mutate <name>', 'serve' or 'list'",
+        zh: "请指定命令：'watch'、'all'、'next'、'hash'、'hint <名称>'、'logs <名称>'、'new <名称>'、'reset <名称>'、'verify-solutions'、'mutate <名称>'、'serve' 或 'list'",
+    }
+    invalid_language(value) {
+        en: "Unsupported language: {value} (expected zh or en)",
+        zh: "不支持的语言：{value}（可选 zh 或 en）",
+    }
+    config_load_failed(error) {
+        en: "Failed to load config file: {error}",
+        zh: "加载配置文件失败：{error}",
+    }
+    unknown_stage(name) {
+        en: "Unknown stage: {name}",
+        zh: "未知的阶段：{name}",
+    }
+    config_save_failed(error) {
+        en: "Error saving config file: {error}",
+        zh: "保存配置文件失败：{error}",
+    }
+    log_write_failed(step, error) {
+        en: "Error writing build log {step}: {error}",
+        zh: "写入构建日志 {step} 失败：{error}",
+    }
+    log_read_failed(name, error) {
+        en: "Error reading build logs of {name}: {error}",
+        zh: "读取 {name} 的构建日志失败：{error}",
+    }
+    no_logs(name) {
+        en: "No build logs for {name} yet; grade it first.",
+        zh: "{name} 还没有构建日志，请先评测该习题。",
+    }
+    fixtures_copy_failed(name, error) {
+        en: "Error preparing the test directory of {name}: {error}",
+        zh: "准备 {name} 的测试运行目录失败：{error}",
+    }
+    progress_save_failed(error) {
+        en: "Error saving progress: {error}",
+        zh: "保存评测进度失败：{error}",
+    }
+    resuming_from(name, skipped) {
+        en: "Resuming from {name} ({skipped} already passed)",
+        zh: "从 {name} 继续（已通过 {skipped} 道习题）",
+    }
+    all_exercises_solved() {
+        en: "All exercises are solved!",
+        zh: "所有习题均已通过！",
+    }
+    next_exercise(name, title, path) {
+        en: "Next exercise: {name} - {title} ({path})",
+        zh: "下一道习题：{name} - {title}（{path}）",
+    }
+    editor_failed(editor, error) {
+        en: "Failed to start editor {editor}: {error}",
+        zh: "无法启动编辑器 {editor}：{error}",
+    }
+    grading_interrupted() {
+        en: "Grading interrupted.",
+        zh: "评测已中断。",
+    }
+    hint_state_save_failed(error) {
+        en: "Error saving hint state: {error}",
+        zh: "保存提示进度失败：{error}",
+    }
+    mutation_failed(name, error) {
+        en: "Failed to run mutation testing for {name}: {error}",
+        zh: "对 {name} 进行变异测试失败：{error}",
+    }
+    reset_failed(name, error) {
+        en: "Failed to reset {name}: {error}",
+        zh: "重置 {name} 失败：{error}",
+    }
+    new_usage() {
+        en: "Usage: new --type single_file|cargo_project --difficulty easy|normal|hard [--stage <stage>] <name>",
+        zh: "用法：new --type single_file|cargo_project --difficulty easy|normal|hard [--stage <阶段>] <名称>",
+    }
+    create_exercise_failed(error) {
+        en: "Failed to create exercise: {error}",
+        zh: "创建习题失败：{error}",
+    }
+    invalid_repeat() {
+        en: "--repeat expects a positive number",
+        zh: "--repeat 需要一个正整数",
+    }
+    missing_exercise_name(command) {
+        en: "Please provide an exercise name: '{command} <name>'",
+        zh: "请指定习题名称：'{command} <名称>'",
+    }
+    unknown_exercise(name) {
+        en: "Unknown exercise: {name}",
+        zh: "未知的习题：{name}",
+    }
+    unknown_exercise_type(kind) {
+        en: "Unknown exercise type: {kind}",
+        zh: "未知的习题类型：{kind}",
+    }
+    hash_failed(name, error) {
+        en: "Failed to hash tests of {name}: {error}",
+        zh: "计算 {name} 测试代码的哈希失败：{error}",
+    }
+
+    // 评测过程
+    evaluating(kind, name) {
+        en: "Evaluating {kind}: {name}",
+        zh: "正在评测 {kind}：{name}",
+    }
+    locked(name, missing) {
+        en: "{name}: LOCKED (requires {missing})",
+        zh: "{name}：已锁定（需先完成 {missing}）",
+    }
+    tests_modified(name) {
+        en: "{name}: TESTS MODIFIED",
+        zh: "{name}：测试代码被修改",
+    }
+    rule_violated(name, violation) {
+        en: "{name}: RULE VIOLATED at {violation}",
+        zh: "{name}：违反规则 {violation}",
+    }
+    not_allowed(rule) {
+        en: "`{rule}` is not allowed",
+        zh: "不允许使用 `{rule}`",
+    }
+    test_macro_redefined(name) {
+        en: "`{name}!` must not be redefined or imported under that name; the tests rely on the standard macro",
+        zh: "不能重新定义 `{name}!` 或以该名称导入其他宏，测试依赖标准库中的这个宏",
+    }
+    rule_check_failed(name, error) {
+        en: "Failed to check rules of {name}: {error}",
+        zh: "检查 {name} 的编写规则失败：{error}",
+    }
+    test_passed(path) {
+        en: "{path}: TEST PASSED",
+        zh: "{path}：测试通过",
+    }
+    test_failed(path) {
+        en: "{path}: TEST FAILED",
+        zh: "{path}：测试未通过",
+    }
+    test_failure(test, location) {
+        en: "{test} failed at {location}",
+        zh: "{test} 未通过（{location}）",
+    }
+    assertion_left(value) {
+        en: " left: {value}",
+        zh: "左值：{value}",
+    }
+    assertion_right(value) {
+        en: "right: {value}",
+        zh: "右值：{value}",
+    }
+    test_flaky(path, passes, repeat) {
+        en: "{path}: TEST FLAKY ({passes}/{repeat} runs passed)",
+        zh: "{path}：测试结果不稳定（{repeat} 次中通过 {passes} 次）",
+    }
+    tests_not_run(passed, expected) {
+        en: "Only {passed} of {expected} tests passed; the rest were not run",
+        zh: "测试代码中共有 {expected} 个测试，只通过了 {passed} 个，其余测试没有运行",
+    }
+    test_run_failed(path) {
+        en: "Error running test executable for {path}",
+        zh: "运行 {path} 的测试程序出错",
+    }
+    remove_binary_failed(path, error) {
+        en: "Failed to remove test binary {path}: {error}",
+        zh: "删除测试程序 {path} 失败：{error}",
+    }
+    removed_binary(path) {
+        en: "Successfully removed test binary: {path}",
+        zh: "已删除测试程序：{path}",
+    }
+    compilation_failed(path) {
+        en: "{path}: COMPILATION FAILED",
+        zh: "{path}：编译失败",
+    }
+    rustc_failed(path) {
+        en: "Error executing rustc --test for {path}",
+        zh: "对 {path} 执行 rustc --test 出错",
+    }
+    project_passed(path) {
+        en: "{path}: PASSED",
+        zh: "{path}：通过",
+    }
+    project_flaky(path, passes, repeat) {
+        en: "{path}: FLAKY ({passes}/{repeat} test runs passed)",
+        zh: "{path}：测试结果不稳定（{repeat} 次中通过 {passes} 次）",
+    }
+    project_failed(path) {
+        en: "{path}: FAILED",
+        zh: "{path}：未通过",
+    }
+    clean_target_failed(error) {
+        en: "Failed to clean up target directory: {error}",
+        zh: "清理 target 目录失败：{error}",
+    }
+    cleaned_target(path) {
+        en: "Successfully cleaned up target directory in: {path}",
+        zh: "已清理 target 目录：{path}",
+    }
+    press_to_continue() {
+        en: "Press any key to continue, or 'q' to quit.",
+        zh: "按回车键继续，输入 'q' 退出。",
+    }
+    case_failed(index, call, expected, actual) {
+        en: "case {index}: {call} expected {expected}, got {actual}",
+        zh: "用例 {index}：{call} 期望 {expected}，实际为 {actual}",
+    }
+    case_panicked(index, call, message) {
+        en: "case {index}: {call} panicked: {message}",
+        zh: "用例 {index}：{call} 发生 panic：{message}",
+    }
+    case_not_run(index, call) {
+        en: "case {index}: {call} produced no result (the test program exited early)",
+        zh: "用例 {index}：{call} 没有结果（测试程序提前退出）",
+    }
+    cases_passed(path, passed, total) {
+        en: "{path}: {passed}/{total} CASES PASSED",
+        zh: "{path}：通过 {passed}/{total} 个用例",
+    }
+    cases_load_failed(name, error) {
+        en: "Failed to prepare cases for {name}: {error}",
+        zh: "准备 {name} 的测试用例失败：{error}",
+    }
+    stress_testing(name) {
+        en: "Stress testing {name} with overflow checks in release mode",
+        zh: "正在以 release 模式并开启溢出检查对 {name} 进行压力测试",
+    }
+    stress_passed(path) {
+        en: "{path}: STRESS TEST PASSED",
+        zh: "{path}：压力测试通过",
+    }
+    stress_failed(path) {
+        en: "{path}: STRESS TEST FAILED",
+        zh: "{path}：压力测试未通过",
+    }
+
+    // 汇总
+    summary() {
+        en: "Summary:",
+        zh: "评测汇总：",
+    }
+    total_exercises(count) {
+        en: "Total exercises: {count}",
+        zh: "习题总数：{count}",
+    }
+    total_successes(count) {
+        en: "Total successes: {count}",
+        zh: "通过：{count}",
+    }
+    total_failures(count) {
+        en: "Total failures: {count}",
+        zh: "未通过：{count}",
+    }
+    total_interrupted(count) {
+        en: "Interrupted (not graded): {count}",
+        zh: "已中断（未评测）：{count}",
+    }
+    total_score(score) {
+        en: "Total score: {score}",
+        zh: "总分：{score}",
+    }
+    total_score_of(score, total) {
+        en: "Total score: {score} / {total}",
+        zh: "总分：{score} / {total}",
+    }
+    interrupted() {
+        en: "Grading interrupted; unfinished exercises are marked as interrupted in the report.",
+        zh: "评测已中断，未完成的习题在报告中标记为 interrupted。",
+    }
+    report_save_failed(error) {
+        en: "Error saving report: {error}",
+        zh: "保存评测报告失败：{error}",
+    }
+    slowest_exercises() {
+        en: "Slowest exercises:",
+        zh: "耗时最长的习题：",
+    }
+    exercise_timing(name, compile_ms, test_ms, peak_kb) {
+        en: "{name}: compile {compile_ms} ms, test {test_ms} ms, peak memory {peak_kb} KB",
+        zh: "{name}：编译 {compile_ms} ms，测试 {test_ms} ms，峰值内存 {peak_kb} KB",
+    }
+
+    // list 命令中的状态
+    status_passed() {
+        en: "passed",
+        zh: "已通过",
+    }
+    status_failed() {
+        en: "failed",
+        zh: "未通过",
+    }
+    status_tampered() {
+        en: "tampered",
+        zh: "测试被修改",
+    }
+    status_flaky() {
+        en: "flaky",
+        zh: "不稳定",
+    }
+    status_interrupted() {
+        en: "interrupted",
+        zh: "已中断",
+    }
+    status_pending() {
+        en: "pending",
+        zh: "未评测",
+    }
+    status_locked() {
+        en: "locked",
+        zh: "已锁定",
+    }
+    requires(missing) {
+        en: "(requires {missing})",
+        zh: "（需先完成 {missing}）",
+    }
+
+    // 时间缩放
+    time_scale(scale) {
+        en: "Time scale: {scale}",
+        zh: "时间缩放系数：{scale}",
+    }
+    calibration_failed(error) {
+        en: "Calibration failed, using time scale 1: {error}",
+        zh: "基准测试失败，时间缩放系数取 1：{error}",
+    }
+
+    // 隐藏测试
+    hidden_skipped(name, env) {
+        en: "{name}: hidden tests skipped ({env} not set)",
+        zh: "{name}：未设置 {env}，跳过隐藏测试",
+    }
+    hidden_read_failed(name, error) {
+        en: "Failed to read hidden tests for {name}: {error}",
+        zh: "读取 {name} 的隐藏测试失败：{error}",
+    }
+    hidden_prepare_failed(name, error) {
+        en: "Failed to prepare hidden tests for {name}: {error}",
+        zh: "准备 {name} 的隐藏测试失败：{error}",
+    }
+    hidden_run_failed(name, error) {
+        en: "Failed to run hidden tests for {name}: {error}",
+        zh: "运行 {name} 的隐藏测试失败：{error}",
+    }
+    hidden_passed(name) {
+        en: "{name}: HIDDEN TESTS PASSED",
+        zh: "{name}：隐藏测试通过",
+    }
+    hidden_failed(name) {
+        en: "{name}: HIDDEN TESTS FAILED",
+        zh: "{name}：隐藏测试未通过",
+    }
+
+    // 内存检查
+    memory_prepare_failed(name, error) {
+        en: "Failed to prepare memory check for {name}: {error}",
+        zh: "准备 {name} 的内存检查失败：{error}",
+    }
+    memory_check_failed_to_run(name, error) {
+        en: "Failed to check memory of {name}: {error}",
+        zh: "检查 {name} 的内存占用失败：{error}",
+    }
+    memory_usage(name, test, leaked, peak) {
+        en: "{name}::{test}: leaked {leaked} bytes, peak {peak} bytes",
+        zh: "{name}::{test}：泄漏 {leaked} 字节，峰值 {peak} 字节",
+    }
+    memory_call_not_measured(name) {
+        en: "{name}: the configured memory_call did not report any measurements",
+        zh: "{name}：配置的 memory_call 没有得到任何统计结果",
+    }
+    leak_limit_exceeded() {
+        en: "leak limit exceeded",
+        zh: "超出泄漏上限",
+    }
+    peak_limit_exceeded() {
+        en: "peak limit exceeded",
+        zh: "超出峰值上限",
+    }
+    memory_passed(name) {
+        en: "{name}: MEMORY CHECK PASSED",
+        zh: "{name}：内存检查通过",
+    }
+    memory_failed(name) {
+        en: "{name}: MEMORY CHECK FAILED",
+        zh: "{name}：内存检查未通过",
+    }
+
+    // 复杂度检查
+    complexity_skipped(name) {
+        en: "{name}: complexity check skipped (only single-file exercises are supported)",
+        zh: "{name}：跳过复杂度检查（仅支持单文件习题）",
+    }
+    unknown_complexity_class(name, class) {
+        en: "Unknown complexity class for {name}: {class}",
+        zh: "{name} 的复杂度类别无法识别：{class}",
+    }
+    measuring_complexity(name, class) {
+        en: "Measuring running time of {name} (expected {class})",
+        zh: "正在测量 {name} 的运行时间（期望 {class}）",
+    }
+    complexity_prepare_failed(name, error) {
+        en: "Failed to prepare complexity check for {name}: {error}",
+        zh: "准备 {name} 的复杂度检查失败：{error}",
+    }
+    not_enough_measurements(name) {
+        en: "Not enough measurements for {name}",
+        zh: "{name} 的测量数据不足",
+    }
+    complexity_timed_out(name, class) {
+        en: "{name}: COMPLEXITY CHECK TIMED OUT (expected {class})",
+        zh: "{name}：复杂度检查超时（期望 {class}）",
+    }
+    measure_failed(name, error) {
+        en: "Failed to measure {name}: {error}",
+        zh: "测量 {name} 失败：{error}",
+    }
+    closest_fit(fitted, class, excess) {
+        en: "closest fit: {fitted}, growth beyond {class}: n^{excess}",
+        zh: "最接近：{fitted}，超出 {class} 的增长：n^{excess}",
+    }
+    complexity_exceeds_warning(name, class) {
+        en: "{name}: COMPLEXITY EXCEEDS {class} (warning only)",
+        zh: "{name}：复杂度超出 {class}（仅警告）",
+    }
+    complexity_exceeds(name, class) {
+        en: "{name}: COMPLEXITY EXCEEDS {class}",
+        zh: "{name}：复杂度超出 {class}",
+    }
+    complexity_may_exceed(name, class) {
+        en: "{name}: growth may exceed {class}",
+        zh: "{name}：增长可能超出 {class}",
+    }
+    complexity_passed(name) {
+        en: "{name}: COMPLEXITY CHECK PASSED",
+        zh: "{name}：复杂度检查通过",
+    }
+
+    // 提示
+    no_hints(name) {
+        en: "No hints available for {name}",
+        zh: "{name} 没有提示",
+    }
+    hint(index, count, text) {
+        en: "Hint {index}/{count}: {text}",
+        zh: "提示 {index}/{count}：{text}",
+    }
+    all_hints_revealed(name) {
+        en: "All hints for {name} have been revealed.",
+        zh: "{name} 的提示已全部显示。",
+    }
+
+    // 重置
+    confirm_reset(name) {
+        en: "Reset {name} to its original version? Your current version will be backed up. [y/N] ",
+        zh: "将 {name} 恢复为初始版本？当前版本会先备份。[y/N] ",
+    }
+    reset_cancelled() {
+        en: "Reset cancelled.",
+        zh: "已取消重置。",
+    }
+    backed_up(path) {
+        en: "Backed up current version to {path}",
+        zh: "当前版本已备份到 {path}",
+    }
+    reset_done(name) {
+        en: "Reset {name} to its original version.",
+        zh: "已将 {name} 恢复为初始版本。",
+    }
+
+    // 新建习题
+    created(path) {
+        en: "Created {path}",
+        zh: "已创建 {path}",
+    }
+
+    // 临时目录
+    remove_scratch_failed(path, error) {
+        en: "Failed to remove scratch directory {path}: {error}",
+        zh: "删除临时目录 {path} 失败：{error}",
+    }
+
+    // 参考答案校验
+    verifying(name) {
+        en: "Verifying {name}",
+        zh: "正在校验 {name}",
+    }
+    verify_ok(name) {
+        en: "{name}: OK",
+        zh: "{name}：正常",
+    }
+    no_solution_configured(name) {
+        en: "{name}: no reference solution configured",
+        zh: "{name}：未配置参考答案",
+    }
+    no_pristine_copy(name) {
+        en: "{name}: no pristine copy found",
+        zh: "{name}：未找到初始版本",
+    }
+    solution_fails() {
+        en: "reference solution does not pass the tests",
+        zh: "参考答案未通过测试",
+    }
+    solution_exceeds_limits() {
+        en: "reference solution exceeds the configured memory or complexity limits",
+        zh: "参考答案超出配置的内存或复杂度要求",
+    }
+    solution_grade_failed(error) {
+        en: "failed to grade reference solution: {error}",
+        zh: "评测参考答案失败：{error}",
+    }
+    solution_breaks_rule(violation) {
+        en: "reference solution breaks a rule at {violation}",
+        zh: "参考答案违反规则 {violation}",
+    }
+    solution_rule_check_failed(error) {
+        en: "failed to check rules of reference solution: {error}",
+        zh: "检查参考答案的编写规则失败：{error}",
+    }
+    solution_not_found(path) {
+        en: "reference solution not found at {path}",
+        zh: "未找到参考答案 {path}",
+    }
+    stub_passes() {
+        en: "pristine stub already passes the tests; tests are too weak",
+        zh: "初始版本已能通过测试，测试过弱",
+    }
+    stub_grade_failed(error) {
+        en: "failed to grade pristine stub: {error}",
+        zh: "评测初始版本失败：{error}",
+    }
+    stub_does_not_compile() {
+        en: "pristine stub does not compile",
+        zh: "初始版本无法编译",
+    }
+    stub_compile_failed(error) {
+        en: "failed to compile pristine stub: {error}",
+        zh: "编译初始版本失败：{error}",
+    }
+    verification_summary() {
+        en: "Verification summary:",
+        zh: "校验汇总：",
+    }
+    exercises_verified(count) {
+        en: "Exercises verified: {count}",
+        zh: "已校验习题：{count}",
+    }
+    exercises_flagged(count) {
+        en: "Exercises flagged: {count}",
+        zh: "存在问题的习题：{count}",
+    }
+
+    // 评测服务
+    invalid_port() {
+        en: "--port expects a port number",
+        zh: "--port 需要一个端口号",
+    }
+    serve_failed(error) {
+        en: "Failed to start grading server: {error}",
+        zh: "启动评测服务失败：{error}",
+    }
+    server_listening(address) {
+        en: "Grading server listening on http://{address}",
+        zh: "评测服务已启动：http://{address}",
+    }
+    submission_queued(name, position) {
+        en: "Received submission for {name} (queue position {position})",
+        zh: "收到 {name} 的提交（排队位置 {position}）",
+    }
+    connection_failed(error) {
+        en: "Failed to handle request: {error}",
+        zh: "处理请求失败：{error}",
+    }
+    origin_not_allowed(origin) {
+        en: "origin not allowed: {origin}",
+        zh: "不接受来自 {origin} 的请求",
+    }
+    json_required() {
+        en: "submission must be application/json",
+        zh: "提交的 Content-Type 必须为 application/json",
+    }
+    invalid_submission(error) {
+        en: "invalid submission: {error}",
+        zh: "提交格式错误：{error}",
+    }
+    queue_not_running() {
+        en: "grading queue is not running",
+        zh: "评测队列未在运行",
+    }
+    submission_grading_failed() {
+        en: "grading failed",
+        zh: "评测失败",
+    }
+    method_not_allowed() {
+        en: "method not allowed",
+        zh: "不支持该请求方法",
+    }
+    route_not_found() {
+        en: "not found",
+        zh: "未找到该路径",
+    }
+    malformed_request() {
+        en: "malformed request line",
+        zh: "请求行格式错误",
+    }
+    invalid_content_length() {
+        en: "invalid Content-Length",
+        zh: "Content-Length 无效",
+    }
+    submission_too_large() {
+        en: "submission too large",
+        zh: "提交内容过大",
+    }
+    invalid_file_path(path) {
+        en: "invalid file path: {path}",
+        zh: "无效的文件路径：{path}",
+    }
+    single_file_only(name, file) {
+        en: "{name} only accepts the file {file}",
+        zh: "{name} 只接受文件 {file}",
+    }
+    src_files_only(name) {
+        en: "{name} only accepts .rs files under src/",
+        zh: "{name} 只接受 src/ 目录下的 .rs 文件",
+    }
+    sandbox_prepare_failed(error) {
+        en: "failed to prepare sandbox: {error}",
+        zh: "准备评测副本失败：{error}",
+    }
+    exercise_copy_failed(error) {
+        en: "failed to copy exercise: {error}",
+        zh: "复制习题失败：{error}",
+    }
+
+    // 变异测试
+    mutants_generated_for(count, name) {
+        en: "Generated {count} mutants for {name}",
+        zh: "为 {name} 生成了 {count} 个变异体",
+    }
+    replace_with(from, to) {
+        en: "replace `{from}` with `{to}`",
+        zh: "将 `{from}` 替换为 `{to}`",
+    }
+    delete_statement(statement) {
+        en: "delete statement `{statement}`",
+        zh: "删除语句 `{statement}`",
+    }
+    mutant_killed() {
+        en: "killed",
+        zh: "已发现",
+    }
+    mutant_timed_out() {
+        en: "killed (timeout)",
+        zh: "已发现（超时）",
+    }
+    mutant_invalid() {
+        en: "does not compile",
+        zh: "无法编译",
+    }
+    mutant_survived() {
+        en: "SURVIVED",
+        zh: "未被发现",
+    }
+    mutation_summary(name) {
+        en: "Mutation summary for {name}:",
+        zh: "{name} 的变异测试汇总：",
+    }
+    mutants_generated(count) {
+        en: "Mutants generated: {count}",
+        zh: "变异体数量：{count}",
+    }
+    mutants_killed(count, timeouts) {
+        en: "Killed: {count} (including {timeouts} timeouts)",
+        zh: "已发现：{count}（其中超时 {timeouts} 个）",
+    }
+    mutants_survived(count) {
+        en: "Survived: {count}",
+        zh: "未被发现：{count}",
+    }
+    mutants_invalid(count) {
+        en: "Not compiling: {count}",
+        zh: "无法编译：{count}",
+    }
+    mutation_score(percent) {
+        en: "Mutation score: {percent}%",
+        zh: "变异得分：{percent}%",
+    }
+    surviving_mutants() {
+        en: "Surviving mutants (the tests do not detect these changes):",
+        zh: "未被发现的变异体（测试未能发现以下改动）：",
+    }
+
+    // 错误详情
+    unsupported_config_format(path) {
+        en: "unsupported config format: {path}",
+        zh: "不支持的配置文件格式：{path}",
+    }
+    working_dir_unsupported(name) {
+        en: "working_dir is not supported for cargo_project {name}",
+        zh: "Cargo 项目 {name} 不支持 working_dir",
+    }
+    unknown_prerequisite(prerequisite, name) {
+        en: "unknown prerequisite {prerequisite} of {name}",
+        zh: "{name} 的先修习题 {prerequisite} 不存在",
+    }
+    dependency_cycle(cycle) {
+        en: "dependency cycle: {cycle}",
+        zh: "先修关系存在循环：{cycle}",
+    }
+    invalid_exercise_name(name) {
+        en: "invalid exercise name: {name}",
+        zh: "无效的习题名称：{name}",
+    }
+    exercise_exists(name) {
+        en: "exercise already exists: {name}",
+        zh: "习题已存在：{name}",
+    }
+    unknown_difficulty(difficulty) {
+        en: "unknown difficulty: {difficulty}",
+        zh: "未知的难度：{difficulty}",
+    }
+    path_exists(path) {
+        en: "{path} already exists",
+        zh: "{path} 已存在",
+    }
+    pristine_not_found(path) {
+        en: "no pristine copy found at {path}",
+        zh: "未找到初始版本 {path}",
+    }
+    solution_missing() {
+        en: "no reference solution configured",
+        zh: "未配置参考答案",
+    }
+    no_test_module(path) {
+        en: "no test module found in {path}",
+        zh: "{path} 中没有找到测试模块",
+    }
+    path_outside_exercise(path) {
+        en: "path must stay inside the exercise: {path}",
+        zh: "路径必须位于习题目录内：{path}",
+    }
+    benchmark_build_failed() {
+        en: "failed to compile calibration benchmark",
+        zh: "编译基准程序失败",
+    }
+    benchmark_output_invalid() {
+        en: "unexpected calibration benchmark output",
+        zh: "无法解析基准程序的输出",
+    }
+    timing_build_failed(output) {
+        en: "timing build failed:\n{output}",
+        zh: "计时程序编译失败：\n{output}",
+    }
+    timing_run_failed() {
+        en: "timing run failed",
+        zh: "计时程序运行失败",
+    }
+    instrumented_build_failed(output) {
+        en: "instrumented build failed:\n{output}",
+        zh: "插桩程序编译失败：\n{output}",
+    }
+    function_cases_missing() {
+        en: "function_cases is not configured",
+        zh: "未配置 function_cases",
+    }
+    invalid_cases(error) {
+        en: "invalid cases: {error}",
+        zh: "用例格式错误：{error}",
+    }
+    cannot_convert(value, ty) {
+        en: "cannot convert {value} to {ty}",
+        zh: "无法把 {value} 转换为 {ty}",
+    }
+    wrong_argument_count(case, count, expected) {
+        en: "case {case} has {count} arguments, expected {expected}",
+        zh: "第 {case} 个用例有 {count} 个参数，应为 {expected} 个",
+    }
+}
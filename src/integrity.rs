@@ -0,0 +1,150 @@
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{cases, config, i18n, runtime, source};
+
+// 读取习题中受保护的测试代码
+// 单文件习题只取 #[cfg(test)] 测试模块，Cargo 项目取 src/tests.rs、它引入的共用测试辅助文件以及 Cargo.toml 中声明它的 [[test]] 测试目标
+// function_cases 习题取用例文件 cases.json，用例内联在配置中时为空
+pub fn protected_test_source(exercise_type: &str, path: &Path) -> io::Result<String> {
+    match exercise_type {
+        "single_file" => {
+            let content = fs::read_to_string(path)?;
+            let range = source::find_test_module(&content).ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, i18n::no_test_module(path.display()))
+            })?;
+            Ok(content[range].to_string())
+        }
+        "cargo_project" => {
+            let mut tests = fs::read_to_string(path.join("src").join("tests.rs"))?;
+            let shared_dir = path.parent().unwrap_or(Path::new("."));
+            for file in runtime::shared_test_files(path) {
+                tests.push('\n');
+                tests.push_str(&fs::read_to_string(shared_dir.join(file))?);
+            }
+            let manifest = fs::read_to_string(path.join("Cargo.toml"))?;
+            Ok(format!("{}\n{}", tests, test_targets(&manifest)?))
+        }
+        "function_cases" => match fs::read_to_string(path.join(cases::CASES_FILE)) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(String::new()),
+            result => result,
+        },
+        _ => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            i18n::unknown_exercise_type(exercise_type),
+        )),
+    }
+}
+
+// Cargo.toml 中的 [[test]] 测试目标，重新序列化后与清单中其余内容的顺序和格式无关
+// 删除或修改测试目标（例如设置 harness = false）都会使哈希改变
+fn test_targets(manifest: &str) -> io::Result<String> {
+    let manifest: toml::Table = manifest.parse().map_err(config::invalid_data)?;
+    let mut targets = toml::Table::new();
+    if let Some(test) = manifest.get("test") {
+        targets.insert("test".to_string(), test.clone());
+    }
+    toml::to_string(&targets).map_err(config::invalid_data)
+}
+
+// 计算测试代码的 SHA-256，统一换行符以免因平台差异误判
+pub fn hash_test_source(test_source: &str) -> String {
+    let normalized = test_source.replace("\r\n", "\n");
+    let digest = Sha256::digest(normalized.as_bytes());
+    digest.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// 受保护的测试代码中 #[test] 测试函数的个数，function_cases 习题的测试由评测程序生成，返回 None
+// 测试程序报告通过的测试少于该数目时，说明部分测试没有被编译或运行
+pub fn expected_test_count(exercise_type: &str, path: &Path) -> Option<usize> {
+    match exercise_type {
+        "single_file" | "cargo_project" => protected_test_source(exercise_type, path).ok().map(|s| source::count_tests(&s)),
+        _ => None,
+    }
+}
+
+pub fn compute_test_hash(exercise_type: &str, path: &Path) -> io::Result<String> {
+    protected_test_source(exercise_type, path).map(|s| hash_test_source(&s))
+}
+
+// 校验测试代码是否与配置中记录的参考哈希一致
+// 未记录哈希的习题视为不受保护
+pub fn is_test_intact(exercise_type: &str, path: &Path, expected: Option<&str>) -> bool {
+    match expected {
+        None => true,
+        Some(expected) => match compute_test_hash(exercise_type, path) {
+            Ok(actual) => actual == expected,
+            Err(_) => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sandbox;
+
+    const TESTS_RS: &str = "#[cfg(test)]\nmod tests {\n    #[test]\n    fn a() {}\n    #[test]\n    fn b() {}\n}\n";
+    const MANIFEST: &str = "[package]\nname = \"p\"\nversion = \"0.1.0\"\n\n[[test]]\nname = \"tests\"\npath = \"src/tests.rs\"\n";
+
+    // 在临时目录中创建只包含 Cargo.toml 与 src/tests.rs 的项目
+    fn cargo_project(label: &str, manifest: &str) -> std::path::PathBuf {
+        let dir = sandbox::create_scratch_dir(label).unwrap();
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("Cargo.toml"), manifest).unwrap();
+        fs::write(dir.join("src").join("tests.rs"), TESTS_RS).unwrap();
+        dir
+    }
+
+    #[test]
+    fn hash_ignores_line_endings() {
+        assert_eq!(hash_test_source("a\r\nb\r\n"), hash_test_source("a\nb\n"));
+        assert_ne!(hash_test_source("a\nb\n"), hash_test_source("a\nc\n"));
+    }
+
+    #[test]
+    fn single_file_hash_covers_only_test_module() {
+        let dir = sandbox::create_scratch_dir("integrity-single-file").unwrap();
+        let file = dir.join("exercise.rs");
+        let hash = |content: &str| {
+            fs::write(&file, content).unwrap();
+            compute_test_hash("single_file", &file).unwrap()
+        };
+        let original = hash(&format!("fn f() {{}}\n{}", TESTS_RS));
+        assert_eq!(hash(&format!("fn f() {{ todo!() }}\nfn g() {{}}\n{}", TESTS_RS)), original);
+        assert_ne!(hash(&format!("fn f() {{}}\n#[cfg(any())]\n{}", TESTS_RS)), original);
+        assert_eq!(expected_test_count("single_file", &file), Some(2));
+        sandbox::remove_scratch_dir(&dir);
+    }
+
+    #[test]
+    fn cargo_hash_covers_test_targets() {
+        let original = cargo_project("integrity-original", MANIFEST);
+        let reformatted = cargo_project(
+            "integrity-reformatted",
+            "[[test]]\npath = \"src/tests.rs\"\nname = \"tests\"\n\n[package]\nname = \"p\"\nversion = \"0.2.0\"\n[dependencies]\n",
+        );
+        let removed = cargo_project("integrity-removed", "[package]\nname = \"p\"\nversion = \"0.1.0\"\n");
+        let no_harness = cargo_project("integrity-no-harness", &format!("{}harness = false\n", MANIFEST));
+        let hash = |dir: &Path| compute_test_hash("cargo_project", dir).unwrap();
+        assert_eq!(hash(&reformatted), hash(&original));
+        assert_ne!(hash(&removed), hash(&original));
+        assert_ne!(hash(&no_harness), hash(&original));
+        assert_eq!(expected_test_count("cargo_project", &original), Some(2));
+        for dir in [original, reformatted, removed, no_harness] {
+            sandbox::remove_scratch_dir(&dir);
+        }
+    }
+
+    #[test]
+    fn missing_test_module_is_not_intact() {
+        let dir = sandbox::create_scratch_dir("integrity-missing").unwrap();
+        let file = dir.join("exercise.rs");
+        fs::write(&file, "fn f() {}\n").unwrap();
+        assert!(!is_test_intact("single_file", &file, Some("0")));
+        assert!(is_test_intact("single_file", &file, None));
+        sandbox::remove_scratch_dir(&dir);
+    }
+}
@@ -0,0 +1,67 @@
+use std::io;
+use std::process::{Child, Command};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::i18n;
+
+// 收到 SIGINT 或 SIGTERM 后置位，评测在当前步骤结束后停止
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+#[cfg(unix)]
+extern "C" fn handle_signal(_: libc::c_int) {
+    // 再次收到信号时不再等待清理，立即退出
+    if INTERRUPTED.swap(true, Ordering::SeqCst) {
+        // SAFETY: _exit 是异步信号安全的
+        unsafe { libc::_exit(130) };
+    }
+}
+
+// 安装 SIGINT 与 SIGTERM 的处理函数
+// 不设置 SA_RESTART，使等待子进程的 wait4 被信号打断，以便及时结束子进程
+#[cfg(unix)]
+pub fn install_handlers() {
+    for signal in [libc::SIGINT, libc::SIGTERM] {
+        // SAFETY: sigaction 是只包含整数与函数指针的 C 结构体，全零是合法值；
+        // 处理函数只访问原子变量与调用 _exit
+        unsafe {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            action.sa_sigaction = handle_signal as extern "C" fn(libc::c_int) as libc::sighandler_t;
+            libc::sigemptyset(&mut action.sa_mask);
+            libc::sigaction(signal, &action, std::ptr::null_mut());
+        }
+    }
+}
+
+// 其他平台保留默认的 Ctrl-C 行为，直接结束评测器
+#[cfg(not(unix))]
+pub fn install_handlers() {}
+
+pub fn is_interrupted() -> bool {
+    INTERRUPTED.load(Ordering::SeqCst)
+}
+
+pub fn interrupted_error() -> io::Error {
+    io::Error::new(io::ErrorKind::Interrupted, i18n::grading_interrupted())
+}
+
+// 让命令在独立的进程组中运行，以便结束它启动的全部进程；其他平台不做处理
+pub fn isolate(command: &mut Command) -> &mut Command {
+    #[cfg(unix)]
+    std::os::unix::process::CommandExt::process_group(command, 0);
+    command
+}
+
+// 结束以 child 为组长的整个进程组（包括 cargo 启动的编译与测试进程）
+#[cfg(unix)]
+pub fn kill_process_group(child: &mut Child) {
+    // SAFETY: 向子进程组发送信号，不涉及内存安全
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+    }
+}
+
+// 其他平台没有进程组，只结束子进程本身
+#[cfg(not(unix))]
+pub fn kill_process_group(child: &mut Child) {
+    let _ = child.kill();
+}
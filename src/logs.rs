@@ -0,0 +1,120 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::i18n;
+use crate::metrics::{self, Measured};
+
+// 每道习题最近一次评测的构建日志保存在该目录下的 `<习题名>/<步骤>.log` 中
+const LOG_DIR: &str = ".grader/logs";
+
+// 日志中额外记录的继承自评测程序的环境变量前缀（命令显式设置的环境变量总会记录）
+const LOGGED_ENV_PREFIXES: &[&str] = &["RUST", "CARGO", "GRADER_"];
+
+fn exercise_log_dir(exercise_name: &str) -> PathBuf {
+    Path::new(LOG_DIR).join(exercise_name)
+}
+
+// 一道习题本次评测的构建日志
+pub struct BuildLog {
+    dir: PathBuf,
+}
+
+impl BuildLog {
+    // 清空习题上一次评测留下的日志
+    pub fn start(exercise_name: &str) -> io::Result<Self> {
+        let dir = exercise_log_dir(exercise_name);
+        if dir.exists() {
+            fs::remove_dir_all(&dir)?;
+        }
+        fs::create_dir_all(&dir)?;
+        Ok(BuildLog { dir })
+    }
+
+    // 写入一次子进程调用的命令行、环境变量、退出状态、耗时与输出
+    pub fn record(&self, step: &str, command: &Command, run: &io::Result<Measured>) -> io::Result<()> {
+        let mut content = format!("$ {}\n", command_line(command));
+        if let Some(dir) = command.get_current_dir() {
+            content.push_str(&format!("cwd: {}\n", dir.display()));
+        }
+        let explicit: Vec<String> = command
+            .get_envs()
+            .filter_map(|(key, value)| Some(format!("{}={}", key.to_string_lossy(), value?.to_string_lossy())))
+            .collect();
+        let inherited = std::env::vars()
+            .filter(|(key, _)| LOGGED_ENV_PREFIXES.iter().any(|prefix| key.starts_with(prefix)))
+            .filter(|(key, _)| command.get_envs().all(|(explicit, _)| explicit != key.as_str()))
+            .map(|(key, value)| format!("{}={}", key, value));
+        for variable in explicit.into_iter().chain(inherited) {
+            content.push_str(&format!("env: {}\n", variable));
+        }
+        match run {
+            Ok(run) => {
+                content.push_str(&format!("status: {}\n", run.status));
+                content.push_str(&format!("elapsed: {} ms\n", run.elapsed_ms()));
+                content.push_str(&format!("peak memory: {} KB\n", run.max_rss_kb));
+                content.push_str(&format!("\n--- stdout ---\n{}", run.stdout));
+                content.push_str(&format!("\n--- stderr ---\n{}", run.stderr));
+            }
+            Err(e) => content.push_str(&format!("error: {}\n", e)),
+        }
+        fs::write(self.dir.join(format!("{}.log", step)), content)
+    }
+
+    // 本次评测写入的日志文件，按写入顺序排列
+    pub fn files(&self) -> Vec<String> {
+        log_files(&self.dir).iter().map(|path| path.display().to_string()).collect()
+    }
+}
+
+fn command_line(command: &Command) -> String {
+    let program = command.get_program().to_string_lossy().into_owned();
+    let args = command.get_args().map(|arg| {
+        let arg = arg.to_string_lossy();
+        if arg.contains(char::is_whitespace) {
+            format!("{:?}", arg)
+        } else {
+            arg.into_owned()
+        }
+    });
+    std::iter::once(program).chain(args).collect::<Vec<_>>().join(" ")
+}
+
+fn log_files(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut files: Vec<(std::time::SystemTime, PathBuf)> = entries
+        .flatten()
+        .filter(|entry| entry.path().extension().is_some_and(|ext| ext == "log"))
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .collect();
+    files.sort();
+    files.into_iter().map(|(_, path)| path).collect()
+}
+
+// 运行命令并记录耗时与峰值内存，log 不为空时把这次调用写入 `<步骤>.log`
+pub fn run_and_record(log: Option<&BuildLog>, step: &str, command: &mut Command) -> io::Result<Measured> {
+    let run = metrics::run_measured(command);
+    if let Some(log) = log {
+        if let Err(e) = log.record(step, command, &run) {
+            eprintln!("{}", i18n::log_write_failed(step, e));
+        }
+    }
+    run
+}
+
+// 打印习题最近一次评测的全部日志
+pub fn print_logs(exercise_name: &str) -> io::Result<()> {
+    let files = log_files(&exercise_log_dir(exercise_name));
+    if files.is_empty() {
+        println!("{}", i18n::no_logs(exercise_name));
+        return Ok(());
+    }
+    for file in files {
+        println!("\x1b[1m==> {} <==\x1b[0m", file.display());
+        println!("{}", fs::read_to_string(&file)?);
+    }
+    Ok(())
+}
@@ -3,29 +3,91 @@ use std::fs::{self, File};
 use std::path::{Path, PathBuf};
 use std::process::{Command, exit};
 use std::time::Instant;
-use std::io::{self, Write};
-
-#[derive(Serialize, Deserialize, Debug, Clone)]
-struct Exercise {
-    name: String,
-    path: String,
-    #[serde(rename = "type")]
-    exercise_type: String,  
-    score: i32, 
-}
-
-#[derive(Serialize, Deserialize, Debug)]
-struct ExerciseConfig {
-    easy: Vec<Exercise>,
-    normal: Vec<Exercise>,
-    hard: Vec<Exercise>,
-}
+use std::collections::{HashMap, HashSet};
+use std::io;
+
+mod assertions;
+mod calibrate;
+mod cases;
+mod compile;
+mod complexity;
+mod config;
+mod constraints;
+mod hidden;
+mod hints;
+mod i18n;
+mod integrity;
+mod interrupt;
+mod logs;
+mod memory;
+mod metadata;
+mod metrics;
+mod mutate;
+mod progress;
+mod reset;
+mod runtime;
+mod sandbox;
+mod scaffold;
+mod server;
+mod source;
+mod verify;
+
+use config::{CourseMetadata, Exercise, ExerciseConfig};
+use logs::BuildLog;
+use metrics::Metrics;
 
 #[derive(Serialize, Deserialize, Debug)]
 struct ExerciseResult {
     name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    difficulty: Option<String>,
     result: bool,
     score: i32, 
+    status: ExerciseStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hidden_result: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    stress_result: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    memory_result: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    complexity_result: Option<bool>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    constraint_violations: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    hints_used: Option<usize>,
+    // 重复运行测试时的通过比例
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pass_rate: Option<f64>,
+    // function_cases 习题通过的用例数与用例总数
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cases_passed: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    cases_total: Option<usize>,
+    // 本次评测写入的构建日志文件
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    logs: Vec<String>,
+    #[serde(flatten)]
+    metrics: Metrics,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+enum ExerciseStatus {
+    Passed,
+    Failed,
+    Tampered,
+    Locked,
+    // 重复运行时测试结果时好时坏
+    Flaky,
+    // 评测被 Ctrl-C 等信号中断，未完成评测
+    Interrupted,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -33,182 +95,757 @@ struct Statistics {
     total_exercises: usize,
     total_successes: usize,
     total_failures: usize,
+    // 被中断而未完成评测的习题，不计入未通过
+    #[serde(default)]
+    total_interrupted: usize,
     total_score: i32,  
     total_time: u64,
 }
 
+// 评测命令的运行选项
+struct GradeOptions<'a> {
+    stage: Option<&'a str>,
+    // 只评测带有该标签的习题
+    tag: Option<&'a str>,
+    // 通过后再以溢出检查模式重新评测
+    stress: bool,
+    // 通过后再以计数分配器检查内存泄漏与峰值占用
+    memory: bool,
+    // 通过后再测量不同输入规模下的耗时，检查时间复杂度
+    complexity: bool,
+    // 每道习题测试的运行次数，用于发现结果不稳定的测试
+    repeat: usize,
+    // 在报告中记录已查看的提示数量
+    record_hints: bool,
+    // 将构建与测试输出保存到 .grader/logs
+    build_log: bool,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct Report {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    course: Option<CourseMetadata>,
+    // 本次评测使用的时间缩放系数
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    time_scale: Option<f64>,
     exercises: Vec<ExerciseResult>,
     statistics: Statistics,
 }
 
 fn main() {
     let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Please provide a command: 'watch' or 'all'");
-        exit(1);
+    let (positionals, options) = parse_args(&args[1..]);
+    // 未指定 --lang 时按系统区域设置选择输出语言
+    if let Some(value) = options.get("lang") {
+        match value.parse() {
+            Ok(lang) => i18n::set_language(lang),
+            Err(_) => {
+                eprintln!("{}", i18n::invalid_language(value));
+                exit(1);
+            }
+        }
     }
-
-    let mode = &args[1];
+    let Some(&mode) = positionals.first() else {
+        eprintln!("{}", i18n::missing_command());
+        exit(1);
+    };
     let start_time = Instant::now();
 
-    
-    let config = match load_exercise_config("exercise_config.json") {
+    let config_path = options.get("config").copied().unwrap_or(config::default_config_path());
+    let stage = options.get("stage").copied();
+    let mut config = match config::load_exercise_config(config_path) {
         Ok(cfg) => cfg,
         Err(e) => {
-            eprintln!("Failed to load config file: {}", e);
+            eprintln!("{}", i18n::config_load_failed(e));
+            exit(1);
+        }
+    };
+    if let Some(name) = stage {
+        if !config.stages.iter().any(|s| s.name == name) {
+            eprintln!("{}", i18n::unknown_stage(name));
+            exit(1);
+        }
+    }
+
+    if mode == "hash" {
+        update_test_hashes(&mut config);
+        if let Err(e) = config::save_exercise_config(config_path, &config) {
+            eprintln!("{}", i18n::config_save_failed(e));
+            exit(1);
+        }
+        return;
+    }
+
+    if mode == "list" {
+        list_exercises(&config, stage, options.get("tag").copied());
+        return;
+    }
+
+    if mode == "hint" {
+        let exercise = require_exercise(&config, &positionals, mode);
+        if let Err(e) = hints::reveal_next_hint(&exercise) {
+            eprintln!("{}", i18n::hint_state_save_failed(e));
+        }
+        return;
+    }
+
+    if mode == "verify-solutions" {
+        interrupt::install_handlers();
+        let verified = verify::verify_solutions(&config.exercises(stage));
+        if interrupt::is_interrupted() {
+            eprintln!("{}", i18n::grading_interrupted());
+            exit(130);
+        }
+        if !verified {
+            exit(1);
+        }
+        return;
+    }
+
+    if mode == "mutate" {
+        let exercise = require_exercise(&config, &positionals, mode);
+        interrupt::install_handlers();
+        if let Err(e) = mutate::mutate_exercise(&exercise) {
+            if interrupt::is_interrupted() {
+                eprintln!("{}", i18n::grading_interrupted());
+                exit(130);
+            }
+            eprintln!("{}", i18n::mutation_failed(&exercise.name, e));
             exit(1);
         }
+        return;
+    }
+
+    if mode == "logs" {
+        let exercise = require_exercise(&config, &positionals, mode);
+        if let Err(e) = logs::print_logs(&exercise.name) {
+            eprintln!("{}", i18n::log_read_failed(&exercise.name, e));
+            exit(1);
+        }
+        return;
+    }
+
+    if mode == "reset" {
+        let exercise = require_exercise(&config, &positionals, mode);
+        if let Err(e) = reset::reset_exercise(&exercise) {
+            eprintln!("{}", i18n::reset_failed(&exercise.name, e));
+            exit(1);
+        }
+        return;
+    }
+
+    if mode == "new" {
+        let (Some(name), Some(exercise_type)) = (positionals.get(1), options.get("type")) else {
+            eprintln!("{}", i18n::new_usage());
+            exit(1);
+        };
+        let difficulty = options.get("difficulty").copied().unwrap_or("easy");
+        if let Err(e) = scaffold::create_exercise(&mut config, stage, exercise_type, difficulty, name) {
+            eprintln!("{}", i18n::create_exercise_failed(e));
+            exit(1);
+        }
+        if let Err(e) = config::save_exercise_config(config_path, &config) {
+            eprintln!("{}", i18n::config_save_failed(e));
+            exit(1);
+        }
+        return;
+    }
+
+    let port = options.get("port").map(|value| value.parse::<u16>());
+    // 允许跨域提交的页面来源，多个来源以逗号分隔
+    let allowed_origins: Vec<&str> = options
+        .get("allow-origin")
+        .map(|value| value.split(',').map(str::trim).filter(|origin| !origin.is_empty()).collect())
+        .unwrap_or_default();
+    let options = GradeOptions {
+        stage,
+        tag: options.get("tag").copied(),
+        stress: options.contains_key("stress"),
+        memory: options.contains_key("memory"),
+        complexity: options.contains_key("complexity"),
+        repeat: match options.get("repeat").map(|value| value.parse::<usize>()) {
+            None => 1,
+            Some(Ok(repeat)) if repeat > 0 => repeat,
+            Some(_) => {
+                eprintln!("{}", i18n::invalid_repeat());
+                exit(1);
+            }
+        },
+        record_hints: config.record_hints,
+        build_log: true,
     };
 
+    if mode == "next" {
+        calibrate::calibrate_time_scale();
+        interrupt::install_handlers();
+        progress::grade_next(&config, &options);
+        if interrupt::is_interrupted() {
+            eprintln!("{}", i18n::grading_interrupted());
+            exit(130);
+        }
+        return;
+    }
+
+    if mode == "serve" {
+        let port = match port {
+            None => DEFAULT_PORT,
+            Some(Ok(port)) => port,
+            Some(Err(_)) => {
+                eprintln!("{}", i18n::invalid_port());
+                exit(1);
+            }
+        };
+        calibrate::calibrate_time_scale();
+        // 提交来自服务的使用者，不记录本地的提示查看情况，也不覆盖本地的构建日志
+        let options = GradeOptions { record_hints: false, build_log: false, ..options };
+        if let Err(e) = server::serve(&config, &options, port, &allowed_origins) {
+            eprintln!("{}", i18n::serve_failed(e));
+            exit(1);
+        }
+        return;
+    }
+
     let mut report = Report {
+        course: Some(config.course.clone()).filter(|course| *course != CourseMetadata::default()),
+        time_scale: Some(calibrate::calibrate_time_scale()),
         exercises: Vec::new(),
         statistics: Statistics {
             total_exercises: 0,
             total_successes: 0,
             total_failures: 0,
+            total_interrupted: 0,
             total_score: 0,
             total_time: 0,
         },
     };
-
-    
-    evaluate_exercises_from_config(mode, config, &mut report);
+    interrupt::install_handlers();
+    evaluate_exercises_from_config(mode, &config, &options, &mut report);
 
     
     report.statistics.total_time = start_time.elapsed().as_secs();
-    report.statistics.total_exercises = report.statistics.total_successes + report.statistics.total_failures;
+    report.statistics.total_exercises = report.statistics.total_successes
+        + report.statistics.total_failures
+        + report.statistics.total_interrupted;
 
     
-    println!("\nSummary:");
-    println!("Total exercises: {}", report.statistics.total_exercises);
-    println!("Total successes: {}", report.statistics.total_successes);
-    println!("Total failures: {}", report.statistics.total_failures);
-    println!("Total score: {}", report.statistics.total_score);
+    println!("\n{}", i18n::summary());
+    println!("{}", i18n::total_exercises(report.statistics.total_exercises));
+    println!("{}", i18n::total_successes(report.statistics.total_successes));
+    println!("{}", i18n::total_failures(report.statistics.total_failures));
+    if report.statistics.total_interrupted > 0 {
+        println!("{}", i18n::total_interrupted(report.statistics.total_interrupted));
+    }
+    match config.course.total_score {
+        Some(total) => println!("{}", i18n::total_score_of(report.statistics.total_score, total)),
+        None => println!("{}", i18n::total_score(report.statistics.total_score)),
+    }
+    print_slowest_exercises(&report.exercises);
 
     
     if let Err(e) = save_report_to_json("report.json", &report) {
-        eprintln!("Error saving report: {}", e);
+        eprintln!("{}", i18n::report_save_failed(e));
+    }
+    if interrupt::is_interrupted() {
+        eprintln!("{}", i18n::interrupted());
+        exit(130);
     }
 }
 
 
-fn load_exercise_config(file_path: &str) -> Result<ExerciseConfig, io::Error> {
-    let file = File::open(file_path)?;
-    let config: ExerciseConfig = serde_json::from_reader(file)?;
-    Ok(config)
-}
+// serve 命令未指定 --port 时使用的端口
+const DEFAULT_PORT: u16 = 8080;
 
+// 汇总中列出的最慢习题数量
+const SLOWEST_SHOWN: usize = 3;
+
+// 按编译与测试总耗时列出最慢的几道习题
+fn print_slowest_exercises(results: &[ExerciseResult]) {
+    let mut measured: Vec<&ExerciseResult> = results.iter().filter(|r| r.metrics.total_ms() > 0).collect();
+    if measured.is_empty() {
+        return;
+    }
+    measured.sort_by_key(|r| std::cmp::Reverse(r.metrics.total_ms()));
+    println!("{}", i18n::slowest_exercises());
+    for r in measured.iter().take(SLOWEST_SHOWN) {
+        println!(
+            "  {}",
+            i18n::exercise_timing(&r.name, r.metrics.compile_ms, r.metrics.test_ms, r.metrics.peak_rss_kb)
+        );
+    }
+}
 
-fn evaluate_exercises_from_config(mode: &str, config: ExerciseConfig, report: &mut Report) {
-    let all_exercises = [config.easy, config.normal, config.hard].concat();
+// 不带值的开关选项
+const SWITCH_OPTIONS: &[&str] = &["stress", "memory", "complexity"];
+
+// 将命令参数拆分为位置参数与 `--key value` 形式的选项
+fn parse_args(args: &[String]) -> (Vec<&str>, HashMap<&str, &str>) {
+    let mut positionals = Vec::new();
+    let mut options = HashMap::new();
+    let mut iter = args.iter();
+    while let Some(arg) = iter.next() {
+        match arg.strip_prefix("--") {
+            Some(key) if SWITCH_OPTIONS.contains(&key) => {
+                options.insert(key, "");
+            }
+            Some(key) => {
+                options.insert(key, iter.next().map_or("", String::as_str));
+            }
+            None => positionals.push(arg.as_str()),
+        }
+    }
+    (positionals, options)
+}
 
-    for exercise in all_exercises {
-        println!("\nEvaluating {}: {}", exercise.exercise_type, exercise.name);
-        let result = evaluate_exercise(&exercise);
+// 取出命令的习题名参数并查找对应习题，找不到时退出
+fn require_exercise(config: &ExerciseConfig, positionals: &[&str], command: &str) -> Exercise {
+    let Some(name) = positionals.get(1) else {
+        eprintln!("{}", i18n::missing_exercise_name(command));
+        exit(1);
+    };
+    let Some(exercise) = config.find_exercise(name) else {
+        eprintln!("{}", i18n::unknown_exercise(name));
+        exit(1);
+    };
+    exercise
+}
 
-        let score = if result { exercise.score } else { 0 };
+// 重新计算并记录每道习题测试代码的参考哈希
+fn update_test_hashes(config: &mut ExerciseConfig) {
+    for exercise in config.exercises_mut() {
+        match integrity::compute_test_hash(&exercise.exercise_type, &exercise_path(exercise)) {
+            Ok(hash) => {
+                println!("{}: {}", exercise.name, hash);
+                exercise.test_hash = Some(hash);
+            }
+            Err(e) => eprintln!("{}", i18n::hash_failed(&exercise.name, e)),
+        }
+    }
+}
 
-        report.exercises.push(ExerciseResult {
-            name: exercise.name.clone(),
-            result,
-            score,
-        });
 
-        if result {
+fn evaluate_exercises_from_config(mode: &str, config: &ExerciseConfig, options: &GradeOptions, report: &mut Report) {
+    let exercises: Vec<Exercise> = config
+        .exercises(options.stage)
+        .into_iter()
+        .filter(|exercise| options.tag.is_none_or(|tag| metadata::exercise_info(exercise).has_tag(tag)))
+        .collect();
+    let mut passed = HashSet::new();
+    // watch 模式从上次的进度继续：跳过开头已通过且之后没有改动的习题
+    let mut skipped = 0;
+    if mode == "watch" {
+        let solved = progress::solved_exercises(&exercises);
+        skipped = exercises.iter().take_while(|exercise| solved.contains_key(&exercise.name)).count();
+        // 跳过的习题沿用上次的评测记录，使报告与统计仍包含它们
+        for exercise in &exercises[..skipped] {
+            let entry = &solved[&exercise.name];
+            report.statistics.total_successes += 1;
+            report.statistics.total_score += entry.score;
+            report.exercises.push(unevaluated_result(exercise, entry.status, entry.score));
+        }
+        passed.extend(solved.into_keys());
+        match exercises.get(skipped) {
+            Some(exercise) if skipped > 0 => println!("{}", i18n::resuming_from(&exercise.name, skipped)),
+            None if skipped > 0 => println!("\x1b[32m{}\x1b[0m", i18n::all_exercises_solved()),
+            _ => {}
+        }
+    }
+    for exercise in exercises.into_iter().skip(skipped) {
+        // 中断后剩余的习题不再评测，正在评测的习题结果不完整，都记录为 interrupted
+        let result = if interrupt::is_interrupted() {
+            unevaluated_result(&exercise, ExerciseStatus::Interrupted, 0)
+        } else {
+            // watch 模式下先修习题未通过时不评测该习题
+            let missing = missing_prerequisites(&exercise, &passed);
+            let locked_by = if mode == "watch" { missing.as_slice() } else { &[] };
+            let result = grade_exercise(&exercise, &exercise_path(&exercise), options, locked_by);
+            if interrupt::is_interrupted() {
+                unevaluated_result(&exercise, ExerciseStatus::Interrupted, 0)
+            } else {
+                progress::record_result(&exercise, &result);
+                result
+            }
+        };
+        if result.result {
+            passed.insert(exercise.name.clone());
             report.statistics.total_successes += 1;
+        } else if result.status == ExerciseStatus::Interrupted {
+            report.statistics.total_interrupted += 1;
         } else {
             report.statistics.total_failures += 1;
         }
+        report.statistics.total_score += result.score;
+        let status = result.status;
+        report.exercises.push(result);
 
-        report.statistics.total_score += score;
-
-        if mode == "watch" && !ask_to_continue() {
+        let waiting = !matches!(status, ExerciseStatus::Locked | ExerciseStatus::Interrupted);
+        if mode == "watch" && waiting && !ask_to_continue() {
             break;
         }
     }
 }
 
+// 没有在本次运行中评测的习题（被中断，或 watch 模式沿用上次结果）只记录状态与得分
+fn unevaluated_result(exercise: &Exercise, status: ExerciseStatus, score: i32) -> ExerciseResult {
+    let info = metadata::exercise_info(exercise);
+    ExerciseResult {
+        name: exercise.name.clone(),
+        title: Some(info.title),
+        description: info.description,
+        tags: info.tags,
+        difficulty: Some(info.difficulty),
+        result: status == ExerciseStatus::Passed,
+        score,
+        status,
+        hidden_result: None,
+        stress_result: None,
+        memory_result: None,
+        complexity_result: None,
+        constraint_violations: Vec::new(),
+        hints_used: None,
+        pass_rate: None,
+        cases_passed: None,
+        cases_total: None,
+        logs: Vec::new(),
+        metrics: Metrics::default(),
+    }
+}
+
+// 评测位于 path 的习题代码（学生目录或提交的副本）并生成评测结果
+// locked_by 不为空时不评测，只记录为锁定状态
+fn grade_exercise(exercise: &Exercise, path: &Path, options: &GradeOptions, locked_by: &[&str]) -> ExerciseResult {
+    let info = metadata::exercise_info(exercise);
+    println!("\n{}", i18n::evaluating(&exercise.exercise_type, &exercise.name));
+    let mut hidden_result = None;
+    let mut stress_result = None;
+    let mut memory_result = None;
+    let mut complexity_result = None;
+    let mut constraint_violations = Vec::new();
+    let mut metrics = Metrics::default();
+    let log = if options.build_log {
+        BuildLog::start(&exercise.name)
+            .map_err(|e| eprintln!("{}", i18n::log_write_failed(&exercise.name, e)))
+            .ok()
+    } else {
+        None
+    };
+    // 除可见测试外的检查均未失败时，function_cases 习题按通过的用例数给分
+    let mut partial_credit = false;
+    let status = if !locked_by.is_empty() {
+        println!("\x1b[33m{}\x1b[0m", i18n::locked(&exercise.name, locked_by.join(", ")));
+        ExerciseStatus::Locked
+    } else if !integrity::is_test_intact(&exercise.exercise_type, path, exercise.test_hash.as_deref()) {
+        println!("\x1b[31m{}\x1b[0m", i18n::tests_modified(&exercise.name));
+        ExerciseStatus::Tampered
+    } else {
+        let visible_passed = evaluate_exercise_at(exercise, path, options.repeat, &mut metrics, log.as_ref());
+        if let Some(output) = metrics.failed_output.as_deref() {
+            assertions::print_failures(output);
+        }
+        hidden_result = hidden::evaluate_hidden_tests(exercise, path);
+        if options.stress && visible_passed {
+            stress_result = Some(evaluate_stress(exercise, path, log.as_ref()));
+        }
+        if options.memory && visible_passed {
+            memory_result = memory::evaluate_memory(exercise, path, log.as_ref());
+        }
+        if options.complexity && visible_passed {
+            complexity_result = complexity::evaluate_complexity(exercise, path, log.as_ref());
+        }
+        match constraints::check_constraints(exercise, path) {
+            Ok(violations) => {
+                for violation in &violations {
+                    println!("\x1b[31m{}\x1b[0m", i18n::rule_violated(&exercise.name, violation));
+                }
+                constraint_violations = violations.iter().map(ToString::to_string).collect();
+            }
+            Err(e) => {
+                eprintln!("{}", i18n::rule_check_failed(&exercise.name, &e));
+                constraint_violations.push(e.to_string());
+            }
+        }
+        let checks = [hidden_result, stress_result, memory_result, complexity_result];
+        partial_credit = !checks.contains(&Some(false)) && constraint_violations.is_empty();
+        if visible_passed && !checks.contains(&Some(false)) && constraint_violations.is_empty() {
+            ExerciseStatus::Passed
+        } else if (1..options.repeat).contains(&metrics.passed_runs) {
+            ExerciseStatus::Flaky
+        } else {
+            ExerciseStatus::Failed
+        }
+    };
+
+    let result = status == ExerciseStatus::Passed;
+    let evaluated = !matches!(status, ExerciseStatus::Locked | ExerciseStatus::Tampered);
+    let pass_rate = (options.repeat > 1 && evaluated).then(|| metrics.passed_runs as f64 / options.repeat as f64);
+    let score = match metrics.cases {
+        // function_cases 习题只按解析出的用例结果给分
+        Some((passed, total)) if (result || partial_credit) && total > 0 => partial_score(exercise.points(), passed, total),
+        _ if result => exercise.points(),
+        _ => 0,
+    };
+    ExerciseResult {
+        name: exercise.name.clone(),
+        title: Some(info.title),
+        description: info.description,
+        tags: info.tags,
+        difficulty: Some(info.difficulty),
+        result,
+        score,
+        status,
+        hidden_result,
+        stress_result,
+        memory_result,
+        complexity_result,
+        constraint_violations,
+        hints_used: options.record_hints.then(|| hints::hints_used(&exercise.name)),
+        pass_rate,
+        cases_passed: metrics.cases.map(|(passed, _)| passed),
+        cases_total: metrics.cases.map(|(_, total)| total),
+        logs: log.map(|log| log.files()).unwrap_or_default(),
+        metrics,
+    }
+}
+
+// 按通过的用例比例给分，四舍五入到整数（如 6 分的习题通过 4/5 个用例得 5 分，1 分的习题通过过半用例得 1 分）
+fn partial_score(points: i32, passed: usize, total: usize) -> i32 {
+    (points as f64 * passed as f64 / total as f64).round() as i32
+}
+
+// 返回尚未通过的先修习题
+fn missing_prerequisites<'a>(exercise: &'a Exercise, passed: &HashSet<String>) -> Vec<&'a str> {
+    exercise
+        .prerequisites
+        .iter()
+        .filter(|name| !passed.iter().any(|p| p == *name || p.strip_suffix(".rs") == Some(name.as_str())))
+        .map(String::as_str)
+        .collect()
+}
+
+// 按依赖顺序列出习题，并根据上一次的评测报告标注状态
+fn list_exercises(config: &ExerciseConfig, stage: Option<&str>, tag: Option<&str>) {
+    let last_results: HashMap<String, ExerciseStatus> = File::open("report.json")
+        .ok()
+        .and_then(|file| serde_json::from_reader::<_, Report>(file).ok())
+        .map(|report| report.exercises.into_iter().map(|r| (r.name, r.status)).collect())
+        .unwrap_or_default();
+    let passed: HashSet<String> = last_results
+        .iter()
+        .filter(|(_, status)| **status == ExerciseStatus::Passed)
+        .map(|(name, _)| name.clone())
+        .collect();
+
+    let mut index = 0;
+    for exercise in &config.exercises(stage) {
+        let info = metadata::exercise_info(exercise);
+        if tag.is_some_and(|tag| !info.has_tag(tag)) {
+            continue;
+        }
+        index += 1;
+        let missing = missing_prerequisites(exercise, &passed);
+        let status = match last_results.get(&exercise.name) {
+            _ if !missing.is_empty() => {
+                format!("\x1b[33m{}\x1b[0m {}", i18n::status_locked(), i18n::requires(missing.join(", ")))
+            }
+            Some(ExerciseStatus::Passed) => format!("\x1b[32m{}\x1b[0m", i18n::status_passed()),
+            Some(ExerciseStatus::Failed) => format!("\x1b[31m{}\x1b[0m", i18n::status_failed()),
+            Some(ExerciseStatus::Tampered) => format!("\x1b[31m{}\x1b[0m", i18n::status_tampered()),
+            Some(ExerciseStatus::Flaky) => format!("\x1b[33m{}\x1b[0m", i18n::status_flaky()),
+            Some(ExerciseStatus::Interrupted) => format!("\x1b[33m{}\x1b[0m", i18n::status_interrupted()),
+            Some(ExerciseStatus::Locked) | None => i18n::status_pending(),
+        };
+        println!(
+            "{:>3}. [{}/{}] {:<16} {:<48} {}",
+            index, exercise.stage, info.difficulty, exercise.name, info.title, status
+        );
+    }
+}
+
 
-fn evaluate_exercise(exercise: &Exercise) -> bool {
-    let exercise_path = PathBuf::from(&format!("./exercises/{}", exercise.path));
+fn exercise_path(exercise: &Exercise) -> PathBuf {
+    PathBuf::from(&format!("./exercises/{}", exercise.path))
+}
+
+
+// 按习题类型评测指定位置的代码（学生目录或其副本）
+// repeat 为测试的运行次数，每次都通过才算通过；log 不为空时保留每一步的构建日志
+fn evaluate_exercise_at(exercise: &Exercise, path: &Path, repeat: usize, metrics: &mut Metrics, log: Option<&BuildLog>) -> bool {
     match exercise.exercise_type.as_str() {
-        "single_file" => evaluate_single_file(&exercise_path),
-        "cargo_project" => evaluate_cargo_project(&exercise_path),
+        "single_file" => evaluate_single_file(exercise, path, false, repeat, metrics, log),
+        "cargo_project" => evaluate_cargo_project(exercise, path, repeat, metrics, log),
+        "function_cases" => cases::evaluate_function_cases(exercise, path, false, repeat, metrics, log),
         _ => {
-            eprintln!("Unknown exercise type: {}", exercise.exercise_type);
+            eprintln!("{}", i18n::unknown_exercise_type(&exercise.exercise_type));
             false
         }
     }
 }
 
+// 压力模式：以开启溢出检查与调试断言的 release 配置重新编译并运行测试
+fn evaluate_stress(exercise: &Exercise, path: &Path, log: Option<&BuildLog>) -> bool {
+    println!("{}", i18n::stress_testing(&exercise.name));
+    match exercise.exercise_type.as_str() {
+        "single_file" => evaluate_single_file(exercise, path, true, 1, &mut Metrics::default(), log),
+        "cargo_project" => {
+            let passed = run_cargo_command(exercise, path, &cargo_test_args(exercise), true, log, "stress-test")
+                .is_ok_and(|run| run.status.success());
+            if passed {
+                println!("\x1b[32m{}\x1b[0m", i18n::stress_passed(path.display()));
+            } else {
+                println!("\x1b[31m{}\x1b[0m", i18n::stress_failed(path.display()));
+            }
+            clean_target_directory(path);
+            passed
+        }
+        "function_cases" => cases::evaluate_function_cases(exercise, path, true, 1, &mut Metrics::default(), log),
+        _ => false,
+    }
+}
+
 // 评测单文件 Rust 习题（使用 rustc --test 并执行测试）
-fn evaluate_single_file(file_path: &PathBuf) -> bool {
+fn evaluate_single_file(
+    exercise: &Exercise,
+    file_path: &Path,
+    stress: bool,
+    repeat: usize,
+    metrics: &mut Metrics,
+    log: Option<&BuildLog>,
+) -> bool {
     // 获取文件名（不带扩展名）
     let test_binary = file_path.with_extension(""); 
+    let [compile_step, test_step] = if stress { ["stress-compile", "stress-test"] } else { ["compile", "test"] };
 
     // 编译测试文件
-    let compile_output = Command::new("rustc")
-        .arg("--test")  // 使用 rustc --test 进行编译
-        .arg(file_path)
-        .arg("-o")
-        .arg(&test_binary)  // 指定输出文件
-        .output();
+    let compile_output = logs::run_and_record(
+        log,
+        compile_step,
+        Command::new("rustc")
+            .arg("--test")  // 使用 rustc --test 进行编译
+            .args(compile::rustc_args(exercise, stress))  // edition、优化级别等习题配置
+            .envs(&exercise.env)
+            .arg(file_path)
+            .arg("-o")
+            .arg(&test_binary),  // 指定输出文件
+    );
 
     if let Ok(output) = compile_output {
+        metrics.compile_ms = output.elapsed_ms();
         if output.status.success() {
-            // 编译成功，运行测试二进制文件
-            let test_output = Command::new(&test_binary)
-                .output();
+            // 编译成功，在习题的运行目录中运行测试二进制文件
+            let test_output = match runtime::prepare(exercise, file_path) {
+                Ok(runtime) => {
+                    let program = runtime::absolute(&test_binary);
+                    let test_command = || {
+                        let mut command = Command::new(&program);
+                        command.args(&exercise.args);
+                        runtime.apply(&mut command, exercise);
+                        command
+                    };
+                    let expected_tests = integrity::expected_test_count(&exercise.exercise_type, file_path);
+                    let completed = |run: &metrics::Measured| metrics::ran_expected_tests(&run.stdout, expected_tests);
+                    let runs = metrics::run_tests_repeatedly(test_command, repeat, completed, metrics, log, test_step);
+                    runtime.finish();
+                    runs
+                }
+                Err(e) => {
+                    eprintln!("{}", i18n::fixtures_copy_failed(&exercise.name, &e));
+                    Err(e)
+                }
+            };
 
             let test_passed = match test_output {
-                Ok(test_run) => {
-                    if test_run.status.success() {
-                        println!("\x1b[32m{}: TEST PASSED\x1b[0m", file_path.display());
-                        true
-                    } else {
-                        println!("\x1b[31m{}: TEST FAILED\x1b[0m", file_path.display());
-                        false
-                    }
+                Ok(passes) if passes == repeat => {
+                    println!("\x1b[32m{}\x1b[0m", i18n::test_passed(file_path.display()));
+                    true
+                }
+                Ok(0) => {
+                    println!("\x1b[31m{}\x1b[0m", i18n::test_failed(file_path.display()));
+                    false
+                }
+                Ok(passes) => {
+                    println!("\x1b[33m{}\x1b[0m", i18n::test_flaky(file_path.display(), passes, repeat));
+                    false
                 }
                 Err(_) => {
-                    eprintln!("Error running test executable for {}", file_path.display());
+                    eprintln!("{}", i18n::test_run_failed(file_path.display()));
                     false
                 }
             };
 
             // 删除测试二进制文件
             if let Err(e) = fs::remove_file(&test_binary) {
-                eprintln!("Failed to remove test binary {}: {}", test_binary.display(), e);
+                eprintln!("{}", i18n::remove_binary_failed(test_binary.display(), e));
             } else {
-                println!("Successfully removed test binary: {}", test_binary.display());
+                println!("{}", i18n::removed_binary(test_binary.display()));
             }
 
-            return test_passed;
+            test_passed
         } else {
             // 编译失败
-            eprintln!("\x1b[31m{}: COMPILATION FAILED\x1b[0m", file_path.display());
-            return false;
+            eprintln!("\x1b[31m{}\x1b[0m", i18n::compilation_failed(file_path.display()));
+            false
         }
     } else {
-        eprintln!("Error executing rustc --test for {}", file_path.display());
-        return false;
+        if interrupt::is_interrupted() {
+            remove_rustc_artifacts(file_path);
+        }
+        eprintln!("{}", i18n::rustc_failed(file_path.display()));
+        false
     }
 }
 
-// 评测 Cargo 项目
-fn evaluate_cargo_project(proj_path: &PathBuf) -> bool {
-    let build_success = run_cargo_command(proj_path, "build");
-    let test_success = run_cargo_command(proj_path, "test");
-    let clippy_success = run_cargo_command(proj_path, "clippy");
+// 删除 rustc 被中途结束时留在习题目录中的中间文件（如 `algorithm1.xxx.rcgu.o` 与 `rustcXXXXXX` 临时目录）
+fn remove_rustc_artifacts(file_path: &Path) {
+    let (Some(dir), Some(stem)) = (file_path.parent(), file_path.file_stem()) else {
+        return;
+    };
+    let prefix = format!("{}.", stem.to_string_lossy());
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let is_rustc_temp_dir = name.len() == 11 && name.starts_with("rustc") && path.is_dir();
+        if is_rustc_temp_dir {
+            let _ = fs::remove_dir_all(&path);
+        } else if name.starts_with(&prefix) && path != file_path {
+            let _ = fs::remove_file(&path);
+        }
+    }
+}
 
-    let passed = build_success && test_success && clippy_success;
+// 评测 Cargo 项目
+fn evaluate_cargo_project(exercise: &Exercise, proj_path: &Path, repeat: usize, metrics: &mut Metrics, log: Option<&BuildLog>) -> bool {
+    if let Err(e) = runtime::prepare(exercise, proj_path).map(runtime::Runtime::finish) {
+        eprintln!("{}", i18n::fixtures_copy_failed(&exercise.name, e));
+    }
+    let build = run_cargo_command(exercise, proj_path, &["build"], false, log, "build");
+    // 先单独编译测试，使 cargo test 的耗时只包含运行测试
+    let test_build = run_cargo_command(exercise, proj_path, &["test", "--no-run"], false, log, "test-build");
+    let test_args = cargo_test_args(exercise);
+    let expected_tests = integrity::expected_test_count(&exercise.exercise_type, proj_path);
+    let test_passes = metrics::run_tests_repeatedly(
+        || cargo_command(exercise, proj_path, &test_args, false),
+        repeat,
+        |run| metrics::ran_expected_tests(&run.stdout, expected_tests),
+        metrics,
+        log,
+        "test",
+    );
+    let clippy = run_cargo_command(exercise, proj_path, &["clippy"], false, log, "clippy");
+
+    metrics.compile_ms = [&build, &test_build].iter().flat_map(|run| run.as_ref().ok()).map(|run| run.elapsed_ms()).sum();
+    let [build_success, clippy_success] = [build, clippy].map(|run| run.is_ok_and(|run| run.status.success()));
+    let test_passes = test_passes.unwrap_or(0);
+
+    let passed = build_success && test_passes == repeat && clippy_success;
 
     if passed {
-        println!("\x1b[32m{}: PASSED\x1b[0m", proj_path.display());
+        println!("\x1b[32m{}\x1b[0m", i18n::project_passed(proj_path.display()));
+    } else if build_success && clippy_success && test_passes > 0 {
+        println!("\x1b[33m{}\x1b[0m", i18n::project_flaky(proj_path.display(), test_passes, repeat));
     } else {
-        println!("\x1b[31m{}: FAILED\x1b[0m", proj_path.display());
+        println!("\x1b[31m{}\x1b[0m", i18n::project_failed(proj_path.display()));
     }
 
     clean_target_directory(proj_path);
@@ -216,28 +853,44 @@ fn evaluate_cargo_project(proj_path: &PathBuf) -> bool {
     passed
 }
 
-// 运行 Cargo 命令
-fn run_cargo_command(proj_path: &PathBuf, command: &str) -> bool {
-    let output = Command::new("cargo")
-        .arg(command)
-        .current_dir(proj_path)
-        .output();
+fn cargo_command(exercise: &Exercise, proj_path: &Path, args: &[&str], stress: bool) -> Command {
+    let mut cargo = Command::new("cargo");
+    cargo.args(args).current_dir(proj_path).envs(&exercise.env);
+    compile::apply_cargo_flags(&mut cargo, exercise, stress);
+    cargo
+}
 
-    match output {
-        Ok(out) => out.status.success(),
-        Err(_) => false,
+// cargo test 的参数，习题配置的 args 放在 `--` 之后传给测试程序
+fn cargo_test_args(exercise: &Exercise) -> Vec<&str> {
+    let mut args = vec!["test"];
+    if !exercise.args.is_empty() {
+        args.push("--");
+        args.extend(exercise.args.iter().map(String::as_str));
     }
+    args
+}
+
+// 运行 Cargo 命令，同时记录耗时与峰值内存，并把输出写入 step 对应的构建日志
+fn run_cargo_command(
+    exercise: &Exercise,
+    proj_path: &Path,
+    args: &[&str],
+    stress: bool,
+    log: Option<&BuildLog>,
+    step: &str,
+) -> io::Result<metrics::Measured> {
+    logs::run_and_record(log, step, &mut cargo_command(exercise, proj_path, args, stress))
 }
 
 // 清理 target 目录
-fn clean_target_directory(proj_path: &PathBuf) {
+fn clean_target_directory(proj_path: &Path) {
     let target_dir = proj_path.join("target");
 
     if target_dir.exists() {
         if let Err(e) = fs::remove_dir_all(&target_dir) {
-            eprintln!("Failed to clean up target directory: {}", e);
+            eprintln!("{}", i18n::clean_target_failed(e));
         } else {
-            println!("Successfully cleaned up target directory in: {}", proj_path.display());
+            println!("{}", i18n::cleaned_target(proj_path.display()));
         }
     }
 }
@@ -245,7 +898,7 @@ fn clean_target_directory(proj_path: &PathBuf) {
 // 用户确认是否继续
 fn ask_to_continue() -> bool {
     let mut input = String::new();
-    println!("\nPress any key to continue, or 'q' to quit.");
+    println!("\n{}", i18n::press_to_continue());
     io::stdin().read_line(&mut input).unwrap();
     input.trim().to_lowercase() != "q"
 }
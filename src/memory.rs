@@ -0,0 +1,298 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+
+use crate::config::MemoryCall;
+use crate::logs::{self, BuildLog};
+use crate::{compile, i18n, runtime, sandbox, source, Exercise};
+
+// 测试程序把每个测试的内存统计追加写入该环境变量指向的文件
+const REPORT_ENV: &str = "ALLOC_PROBE_REPORT";
+
+// 未配置 sizes 时被测函数调用使用的输入规模
+const DEFAULT_CALL_SIZES: &[usize] = &[1_000, 100_000];
+
+// 附加到习题副本中的计数分配器
+// 计数按线程记录，避免并行运行的测试互相干扰；每个测试开头创建 TestProbe，
+// 测试中的局部变量先于 TestProbe 释放，因此 TestProbe 释放时仍未归还的内存即为泄漏
+const PROBE_SOURCE: &str = r#"
+#[cfg(test)]
+mod __alloc_probe {
+    use std::alloc::{GlobalAlloc, Layout, System};
+    use std::cell::Cell;
+    use std::io::Write;
+
+    struct CountingAllocator;
+
+    thread_local! {
+        static CURRENT: Cell<isize> = const { Cell::new(0) };
+        static PEAK: Cell<isize> = const { Cell::new(0) };
+    }
+
+    fn record(delta: isize) {
+        let _ = CURRENT.try_with(|current| {
+            let value = current.get() + delta;
+            current.set(value);
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(value)));
+        });
+    }
+
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            let ptr = unsafe { System.alloc(layout) };
+            if !ptr.is_null() {
+                record(layout.size() as isize);
+            }
+            ptr
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) };
+            record(-(layout.size() as isize));
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            let new_ptr = unsafe { System.realloc(ptr, layout, new_size) };
+            if !new_ptr.is_null() {
+                record(new_size as isize - layout.size() as isize);
+            }
+            new_ptr
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    pub struct TestProbe {
+        name: String,
+        start: isize,
+    }
+
+    impl TestProbe {
+        pub fn new(name: impl Into<String>) -> Self {
+            // 标准输出的缓冲区在首次使用时分配且不会释放，提前初始化以免计入测试
+            let _ = std::io::stdout();
+            let name = name.into();
+            let start = CURRENT.with(Cell::get);
+            PEAK.with(|peak| peak.set(start));
+            TestProbe { name, start }
+        }
+    }
+
+    impl Drop for TestProbe {
+        fn drop(&mut self) {
+            if std::thread::panicking() {
+                return;
+            }
+            let leaked = CURRENT.with(Cell::get) - self.start;
+            let peak = PEAK.with(Cell::get) - self.start;
+            let Some(path) = std::env::var_os("ALLOC_PROBE_REPORT") else {
+                return;
+            };
+            if let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(path) {
+                let _ = writeln!(file, "{} {} {}", self.name, leaked.max(0), peak.max(0));
+            }
+        }
+    }
+}
+"#;
+
+// 对每个规模先生成输入，再只在被测函数调用期间统计内存，调用的返回值在统计结束前释放
+fn call_probe_source(call: &MemoryCall) -> String {
+    let sizes = if call.sizes.is_empty() { DEFAULT_CALL_SIZES } else { &call.sizes };
+    let sizes: Vec<String> = sizes.iter().map(usize::to_string).collect();
+    format!(
+        r#"
+#[cfg(test)]
+mod __memory_call_probe {{
+    use super::*;
+
+    #[test]
+    fn __memory_call_probe() {{
+        for n in [{sizes}] {{
+            let n: usize = n;
+            let input = std::hint::black_box({setup});
+            let probe = crate::__alloc_probe::TestProbe::new(format!("call(n={{}})", n));
+            let output = std::hint::black_box({{ {call} }});
+            drop(output);
+            drop(probe);
+        }}
+    }}
+}}
+"#,
+        sizes = sizes.join(", "),
+        setup = call.setup,
+        call = call.call,
+    )
+}
+
+struct TestUsage {
+    name: String,
+    leaked_bytes: u64,
+    peak_bytes: u64,
+}
+
+// 在测试模块中每个 #[test] 函数体的开头插入 TestProbe
+fn instrument_tests(content: &str) -> String {
+    let Some(module) = source::find_test_module(content) else {
+        return content.to_string();
+    };
+    let attr = "#[test]";
+    let mut insertions = Vec::new();
+    let mut from = module.start;
+    while let Some(start) =
+        source::scan_code(content, from, |i, _| content.as_bytes()[i..].starts_with(attr.as_bytes()).then_some(i))
+    {
+        if start >= module.end {
+            break;
+        }
+        from = start + attr.len();
+        let Some(open) = source::scan_code(content, from, |i, b| (b == b'{').then_some(i)) else {
+            break;
+        };
+        let name = content[from..open]
+            .split_once("fn ")
+            .map(|(_, rest)| rest.split(|c: char| !(c.is_alphanumeric() || c == '_')).next().unwrap_or_default())
+            .unwrap_or_default();
+        insertions.push((open + 1, name.to_string()));
+        from = open;
+    }
+
+    let mut instrumented = content.to_string();
+    for (pos, name) in insertions.into_iter().rev() {
+        instrumented.insert_str(
+            pos,
+            &format!("\n        let _alloc_probe = crate::__alloc_probe::TestProbe::new({:?});", name),
+        );
+    }
+    instrumented.push_str(PROBE_SOURCE);
+    instrumented
+}
+
+fn parse_usage(content: &str) -> Vec<TestUsage> {
+    content
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.split_whitespace();
+            Some(TestUsage {
+                name: fields.next()?.to_string(),
+                leaked_bytes: fields.next()?.parse().ok()?,
+                peak_bytes: fields.next()?.parse().ok()?,
+            })
+        })
+        .collect()
+}
+
+// 编译并运行插桩后的习题副本，返回每个测试以及配置的被测函数调用在各规模下的内存统计
+fn measure(
+    exercise: &Exercise,
+    file_path: &Path,
+    work_dir: &Path,
+    log: Option<&BuildLog>,
+) -> io::Result<(Vec<TestUsage>, Vec<TestUsage>)> {
+    let copy = work_dir.join(file_path.file_name().unwrap_or_default());
+    let mut content = instrument_tests(&fs::read_to_string(file_path)?);
+    if let Some(call) = &exercise.memory_call {
+        content.push_str(&call_probe_source(call));
+    }
+    fs::write(&copy, content)?;
+
+    let binary = copy.with_extension("");
+    let compiled = logs::run_and_record(
+        log,
+        "memory-compile",
+        Command::new("rustc")
+            .arg("--test")
+            .args(compile::rustc_args(exercise, false))
+            .envs(&exercise.env)
+            .arg(&copy)
+            .arg("-o")
+            .arg(&binary),
+    )?;
+    if !compiled.status.success() {
+        return Err(io::Error::other(i18n::instrumented_build_failed(&compiled.stderr)));
+    }
+
+    // --nocapture 使测试输出不经过测试框架的缓冲区，避免缓冲区的分配计入测试
+    // 被测函数调用单独运行，不受习题配置的测试过滤参数影响
+    let runtime = runtime::prepare(exercise, file_path)?;
+    let run = |step: &str, args: &[&str], report: &Path| -> io::Result<Vec<TestUsage>> {
+        let mut command = Command::new(&binary);
+        command.args(args).arg("--nocapture").env(REPORT_ENV, report);
+        runtime.apply(&mut command, exercise);
+        logs::run_and_record(log, step, &mut command)?;
+        Ok(fs::read_to_string(report).map(|content| parse_usage(&content)).unwrap_or_default())
+    };
+    let mut args: Vec<&str> = exercise.args.iter().map(String::as_str).collect();
+    args.extend(["--skip", "__memory_call_probe::"]);
+    let tests = run("memory-test", &args, &work_dir.join("alloc.txt"));
+    let calls = match (&tests, &exercise.memory_call) {
+        (Ok(_), Some(_)) => run(
+            "memory-call",
+            &["__memory_call_probe::__memory_call_probe", "--exact"],
+            &work_dir.join("call.txt"),
+        ),
+        _ => Ok(Vec::new()),
+    };
+    runtime.finish();
+    Ok((tests?, calls?))
+}
+
+// 检查单文件习题测试中的内存泄漏与峰值内存占用
+// 返回 None 表示该习题不支持插桩（Cargo 项目）
+pub fn evaluate_memory(exercise: &Exercise, path: &Path, log: Option<&BuildLog>) -> Option<bool> {
+    if exercise.exercise_type != "single_file" {
+        return None;
+    }
+    let work_dir = match sandbox::create_scratch_dir(&format!("memory-{}", exercise.name)) {
+        Ok(dir) => dir,
+        Err(e) => {
+            eprintln!("{}", i18n::memory_prepare_failed(&exercise.name, e));
+            return Some(false);
+        }
+    };
+    let usage = measure(exercise, path, &work_dir, log);
+    sandbox::remove_scratch_dir(&work_dir);
+
+    let (tests, calls) = match usage {
+        Ok(usage) => usage,
+        Err(e) => {
+            eprintln!("{}", i18n::memory_check_failed_to_run(&exercise.name, e));
+            return Some(false);
+        }
+    };
+    if exercise.memory_call.is_some() && calls.is_empty() {
+        eprintln!("{}", i18n::memory_call_not_measured(&exercise.name));
+        return Some(false);
+    }
+
+    // 测试的峰值包含测试数据本身，配置了 memory_call 时峰值上限只检查被测函数调用
+    let peak_limited = |is_call: bool| is_call == exercise.memory_call.is_some();
+    let mut passed = true;
+    let usage = tests.iter().map(|test| (test, false)).chain(calls.iter().map(|call| (call, true)));
+    for (test, is_call) in usage {
+        let mut problems = Vec::new();
+        if exercise.max_leaked_bytes.is_some_and(|limit| test.leaked_bytes > limit) {
+            problems.push(i18n::leak_limit_exceeded());
+        }
+        if peak_limited(is_call) && exercise.max_peak_bytes.is_some_and(|limit| test.peak_bytes > limit) {
+            problems.push(i18n::peak_limit_exceeded());
+        }
+        let line = i18n::memory_usage(&exercise.name, &test.name, test.leaked_bytes, test.peak_bytes);
+        if !problems.is_empty() {
+            passed = false;
+            println!("\x1b[31m{} ({})\x1b[0m", line, problems.join(", "));
+        } else if test.leaked_bytes > 0 {
+            println!("\x1b[33m{}\x1b[0m", line);
+        } else {
+            println!("{}", line);
+        }
+    }
+    if passed {
+        println!("\x1b[32m{}\x1b[0m", i18n::memory_passed(&exercise.name));
+    } else {
+        println!("\x1b[31m{}\x1b[0m", i18n::memory_failed(&exercise.name));
+    }
+    Some(passed)
+}
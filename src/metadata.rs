@@ -0,0 +1,91 @@
+use std::fs;
+use std::path::PathBuf;
+
+use crate::{exercise_path, Exercise};
+
+// 习题的展示信息：优先取自源码开头的块注释，缺失的部分使用配置中的字段
+pub struct ExerciseInfo {
+    pub title: String,
+    pub description: Option<String>,
+    pub tags: Vec<String>,
+    pub difficulty: String,
+}
+
+impl ExerciseInfo {
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t.eq_ignore_ascii_case(tag))
+    }
+}
+
+#[derive(Default)]
+struct Header {
+    title: Option<String>,
+    description: Option<String>,
+    tags: Vec<String>,
+    difficulty: Option<String>,
+}
+
+// 单文件习题读取文件本身，function_cases 习题读取被测函数所在的源文件，Cargo 项目读取 src/main.rs
+pub fn header_file(exercise: &Exercise) -> PathBuf {
+    let path = exercise_path(exercise);
+    match (exercise.exercise_type.as_str(), &exercise.function_cases) {
+        ("single_file", _) => path,
+        ("function_cases", Some(spec)) => path.join(&spec.source),
+        _ => path.join("src").join("main.rs"),
+    }
+}
+
+// 解析文件开头的 `/* 标题 \n 描述 */` 注释
+// 描述中以 `Tags:` 与 `Difficulty:` 开头的行分别作为标签与难度
+fn parse_header(source: &str) -> Header {
+    let mut header = Header::default();
+    let Some(body) = source.trim_start().strip_prefix("/*") else {
+        return header;
+    };
+    let Some((body, _)) = body.split_once("*/") else {
+        return header;
+    };
+
+    let mut description = Vec::new();
+    for line in body.lines().map(str::trim) {
+        let field = |name: &str| {
+            line.get(..name.len())
+                .filter(|prefix| prefix.eq_ignore_ascii_case(name))
+                .map(|_| line[name.len()..].trim())
+        };
+        if header.title.is_none() {
+            if !line.is_empty() {
+                header.title = Some(line.to_string());
+            }
+        } else if let Some(tags) = field("tags:") {
+            header.tags = tags.split(',').map(str::trim).filter(|t| !t.is_empty()).map(str::to_string).collect();
+        } else if let Some(difficulty) = field("difficulty:") {
+            header.difficulty = Some(difficulty.to_string()).filter(|d| !d.is_empty());
+        } else if !line.is_empty() || description.last().is_some_and(|last: &&str| !last.is_empty()) {
+            description.push(line);
+        }
+    }
+    while description.last().is_some_and(|last| last.is_empty()) {
+        description.pop();
+    }
+    header.description = Some(description.join("\n")).filter(|d| !d.is_empty());
+    header
+}
+
+pub fn exercise_info(exercise: &Exercise) -> ExerciseInfo {
+    let header = fs::read_to_string(header_file(exercise))
+        .map(|source| parse_header(&source))
+        .unwrap_or_default();
+    ExerciseInfo {
+        title: header
+            .title
+            .or_else(|| exercise.title.clone())
+            .unwrap_or_else(|| exercise.name.clone()),
+        description: header.description.or_else(|| exercise.description.clone()),
+        tags: if header.tags.is_empty() { exercise.tags.clone() } else { header.tags },
+        difficulty: header
+            .difficulty
+            .or_else(|| exercise.difficulty.clone())
+            .unwrap_or_else(|| exercise.category.clone()),
+    }
+}
@@ -0,0 +1,185 @@
+use serde::{Deserialize, Serialize};
+use std::io::{self, Read};
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::{i18n, interrupt};
+use crate::logs::{self, BuildLog};
+
+// 单道习题的资源消耗，时间以毫秒、内存以 KB 为单位
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Metrics {
+    pub compile_ms: u64,
+    pub test_ms: u64,
+    // 测试进程（Cargo 项目为 cargo test 及其子进程）的峰值常驻内存
+    pub peak_rss_kb: u64,
+    // 测试通过的次数，用于判断重复运行时结果是否稳定
+    #[serde(skip)]
+    pub passed_runs: usize,
+    // function_cases 习题通过的用例数与用例总数
+    #[serde(skip)]
+    pub cases: Option<(usize, usize)>,
+    // 第一次未通过的测试运行的标准输出，用于列出断言失败的详情
+    #[serde(skip)]
+    pub failed_output: Option<String>,
+}
+
+impl Metrics {
+    pub fn total_ms(&self) -> u64 {
+        self.compile_ms + self.test_ms
+    }
+}
+
+pub struct Measured {
+    pub status: ExitStatus,
+    pub elapsed: Duration,
+    pub max_rss_kb: u64,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl Measured {
+    pub fn elapsed_ms(&self) -> u64 {
+        self.elapsed.as_millis() as u64
+    }
+}
+
+// 汇总 libtest 输出中每个测试程序的 "test result:" 行
+// 返回通过的测试数，以及被忽略或被参数过滤掉的测试数
+fn test_counts(output: &str) -> (usize, usize) {
+    let (mut passed, mut skipped) = (0, 0);
+    for line in output.lines() {
+        let Some((_, counts)) = line.strip_prefix("test result: ").and_then(|rest| rest.split_once(". ")) else {
+            continue;
+        };
+        for count in counts.split("; ") {
+            let Some((number, label)) = count.split_once(' ') else {
+                continue;
+            };
+            let number: usize = number.parse().unwrap_or(0);
+            match label {
+                "passed" => passed += number,
+                "ignored" | "filtered out" => skipped += number,
+                _ => {}
+            }
+        }
+    }
+    (passed, skipped)
+}
+
+// 测试程序正常退出时，检查是否真的运行了测试代码中的全部测试
+// 测试被条件编译去掉或 Cargo 没有找到测试目标时，测试程序同样会报告成功
+pub fn ran_expected_tests(output: &str, expected_tests: Option<usize>) -> bool {
+    let Some(expected) = expected_tests else {
+        return true;
+    };
+    let (passed, skipped) = test_counts(output);
+    if passed + skipped >= expected && (passed > 0 || expected == 0) {
+        return true;
+    }
+    println!("\x1b[31m{}\x1b[0m", i18n::tests_not_run(passed, expected));
+    false
+}
+
+// 重复运行测试 repeat 次，记录平均耗时、最大峰值内存与通过次数
+// 测试程序正常退出后再由 completed 检查这次运行是否真的完成了全部测试，否则不算通过
+// 重复运行时每次的日志分别写入 `<步骤>-<序号>.log`
+pub fn run_tests_repeatedly(
+    mut command: impl FnMut() -> Command,
+    repeat: usize,
+    completed: impl Fn(&Measured) -> bool,
+    metrics: &mut Metrics,
+    log: Option<&BuildLog>,
+    step: &str,
+) -> io::Result<usize> {
+    let mut total = Duration::ZERO;
+    metrics.passed_runs = 0;
+    metrics.failed_output = None;
+    for index in 1..=repeat {
+        let step = if repeat > 1 { format!("{}-{}", step, index) } else { step.to_string() };
+        let run = logs::run_and_record(log, &step, &mut command())?;
+        total += run.elapsed;
+        metrics.peak_rss_kb = metrics.peak_rss_kb.max(run.max_rss_kb);
+        if run.status.success() && completed(&run) {
+            metrics.passed_runs += 1;
+        } else if metrics.failed_output.is_none() {
+            metrics.failed_output = Some(run.stdout);
+        }
+    }
+    metrics.test_ms = (total / repeat.max(1) as u32).as_millis() as u64;
+    Ok(metrics.passed_runs)
+}
+
+// 运行命令并取得其耗时与峰值内存，同时收集命令的标准输出与标准错误
+// 命令在独立进程组中运行，评测被中断时结束整个进程组并返回 Interrupted 错误
+pub fn run_measured(command: &mut Command) -> io::Result<Measured> {
+    if interrupt::is_interrupted() {
+        return Err(interrupt::interrupted_error());
+    }
+    let start = Instant::now();
+    let mut child = interrupt::isolate(command)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // 在后台线程中读取输出，避免管道写满使子进程阻塞
+    let stdout = child.stdout.take().map(read_in_background);
+    let stderr = child.stderr.take().map(read_in_background);
+    let (status, max_rss_kb) = wait_measured(&mut child)?;
+    if interrupt::is_interrupted() {
+        return Err(interrupt::interrupted_error());
+    }
+    Ok(Measured {
+        status,
+        elapsed: start.elapsed(),
+        max_rss_kb,
+        stdout: stdout.map(join_output).unwrap_or_default(),
+        stderr: stderr.map(join_output).unwrap_or_default(),
+    })
+}
+
+// 通过 wait4 等待子进程，返回退出状态与峰值常驻内存（KB）
+#[cfg(unix)]
+fn wait_measured(child: &mut Child) -> io::Result<(ExitStatus, u64)> {
+    use std::os::unix::process::ExitStatusExt;
+
+    let mut status = 0;
+    // SAFETY: rusage 是只包含整数字段的 C 结构体，全零是合法值
+    let mut usage: libc::rusage = unsafe { std::mem::zeroed() };
+    loop {
+        // SAFETY: 等待刚创建且尚未被回收的子进程，指针均指向有效的局部变量
+        let pid = unsafe { libc::wait4(child.id() as libc::pid_t, &mut status, 0, &mut usage) };
+        if pid >= 0 {
+            break;
+        }
+        let error = io::Error::last_os_error();
+        if error.kind() != io::ErrorKind::Interrupted {
+            return Err(error);
+        }
+        if interrupt::is_interrupted() {
+            interrupt::kill_process_group(child);
+        }
+    }
+    // Linux 上 ru_maxrss 的单位为 KB，并包含已回收的子进程
+    Ok((ExitStatus::from_raw(status), usage.ru_maxrss.max(0) as u64))
+}
+
+// 其他平台无法取得子进程的峰值内存，记为 0
+#[cfg(not(unix))]
+fn wait_measured(child: &mut Child) -> io::Result<(ExitStatus, u64)> {
+    Ok((child.wait()?, 0))
+}
+
+fn read_in_background(mut pipe: impl Read + Send + 'static) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        let _ = pipe.read_to_end(&mut buffer);
+        String::from_utf8_lossy(&buffer).into_owned()
+    })
+}
+
+fn join_output(reader: thread::JoinHandle<String>) -> String {
+    reader.join().unwrap_or_default()
+}
@@ -0,0 +1,290 @@
+use std::fs;
+use std::io;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::{cases, compile, i18n, interrupt, runtime, sandbox, source, verify, Exercise};
+
+struct Mutant {
+    file: PathBuf,
+    line: usize,
+    range: Range<usize>,
+    replacement: String,
+    description: String,
+}
+
+enum MutantOutcome {
+    Killed,
+    TimedOut,
+    Survived,
+    // 变异后无法编译，不计入统计
+    Invalid,
+}
+
+// 比较与逻辑运算符的替换规则，仅匹配两侧带空格的二元运算符
+const OPERATOR_MUTATIONS: &[(&str, &[&str])] = &[
+    ("==", &["!="]),
+    ("!=", &["=="]),
+    ("<=", &[">", "<"]),
+    (">=", &["<", ">"]),
+    ("<", &[">=", "<="]),
+    (">", &["<=", ">="]),
+    ("&&", &["||"]),
+    ("||", &["&&"]),
+];
+
+// 需要变异的源文件（相对习题根目录），测试代码与 main.rs 不参与变异
+fn mutable_files(exercise: &Exercise, root: &Path) -> io::Result<Vec<PathBuf>> {
+    if exercise.exercise_type == "single_file" {
+        return Ok(vec![PathBuf::from(root.file_name().unwrap_or_default())]);
+    }
+    let mut files = Vec::new();
+    for entry in fs::read_dir(root.join("src"))? {
+        let path = entry?.path();
+        let is_solution_module = path.file_name().is_some_and(|name| name != "tests.rs" && name != "main.rs");
+        if path.extension().is_some_and(|ext| ext == "rs") && is_solution_module {
+            files.push(Path::new("src").join(path.file_name().unwrap_or_default()));
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+fn line_of(source: &str, pos: usize) -> usize {
+    source[..pos].matches('\n').count() + 1
+}
+
+fn is_ident_byte(b: u8) -> bool {
+    b.is_ascii_alphanumeric() || b == b'_'
+}
+
+// 在源码中生成翻转比较、差一修改与删除语句三类变异
+fn generate_mutants(file: &Path, source: &str) -> Vec<Mutant> {
+    // 测试模块不参与变异，测试模块之后的代码仍然会生成变异
+    let bytes = source.as_bytes();
+    let is_code = source::student_code_mask(source);
+
+    let mut mutants = Vec::new();
+    let mut push = |range: Range<usize>, replacement: String, description: String| {
+        mutants.push(Mutant {
+            file: file.to_path_buf(),
+            line: line_of(source, range.start),
+            range,
+            replacement,
+            description,
+        });
+    };
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if !is_code[i] {
+            i += 1;
+            continue;
+        }
+
+        let spaced = |len: usize| i > 0 && bytes[i - 1] == b' ' && bytes.get(i + len) == Some(&b' ');
+        if let Some((op, replacements)) = OPERATOR_MUTATIONS
+            .iter()
+            .find(|(op, _)| bytes[i..].starts_with(op.as_bytes()) && spaced(op.len()))
+        {
+            for replacement in replacements.iter() {
+                push(i..i + op.len(), replacement.to_string(), i18n::replace_with(op, replacement));
+            }
+            i += op.len();
+            continue;
+        }
+
+        if bytes[i].is_ascii_digit() && (i == 0 || !(is_ident_byte(bytes[i - 1]) || bytes[i - 1] == b'.')) {
+            let end = i + bytes[i..].iter().take_while(|b| b.is_ascii_digit() || **b == b'_').count();
+            let next = bytes.get(end).copied().unwrap_or(b' ');
+            let is_float = next == b'.' && bytes.get(end + 1).is_some_and(u8::is_ascii_digit);
+            let is_radix = bytes[i] == b'0' && matches!(next, b'x' | b'o' | b'b');
+            let is_float_suffix = next == b'e' || next == b'E' || next == b'f';
+            let literal = source[i..end].replace('_', "");
+            if let (false, false, false, Ok(value)) = (is_float, is_radix, is_float_suffix, literal.parse::<u128>()) {
+                push(i..end, (value + 1).to_string(), i18n::replace_with(value, value + 1));
+                if value > 0 {
+                    push(i..end, (value - 1).to_string(), i18n::replace_with(value, value - 1));
+                }
+            }
+            i = end + bytes[end..].iter().take_while(|b| is_ident_byte(**b)).count();
+            continue;
+        }
+
+        i += 1;
+    }
+
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        let trimmed = line.trim();
+        let start = offset + (line.len() - line.trim_start().len());
+        offset += line.len();
+        let skipped_prefixes = ["let ", "use ", "mod ", "return", "const ", "static ", "pub ", "fn ", "type ", "."];
+        if trimmed.ends_with(';')
+            && is_code.get(start).copied().unwrap_or(false)
+            && !trimmed.contains(['{', '}'])
+            && !skipped_prefixes.iter().any(|prefix| trimmed.starts_with(prefix))
+        {
+            push(start..start + trimmed.len(), String::new(), i18n::delete_statement(trimmed));
+        }
+    }
+
+    mutants.sort_by_key(|mutant| mutant.range.start);
+    mutants
+}
+
+// 编译并运行习题测试，返回 None 表示无法编译
+fn run_tests(exercise: &Exercise, root: &Path, timeout: Duration) -> io::Result<Option<Option<bool>>> {
+    let compile_timeout = Duration::from_secs(300);
+    if exercise.exercise_type == "single_file" {
+        let binary = root.with_extension("");
+        let compiled = sandbox::run_with_timeout(
+            Command::new("rustc")
+                .arg("--test")
+                .args(compile::rustc_args(exercise, false))
+                .envs(&exercise.env)
+                .arg(root)
+                .arg("-o")
+                .arg(&binary),
+            compile_timeout,
+        )?;
+        if !compiled.is_some_and(|status| status.success()) {
+            return Ok(None);
+        }
+        let runtime = runtime::prepare(exercise, root)?;
+        let mut command = Command::new(&binary);
+        command.args(&exercise.args);
+        runtime.apply(&mut command, exercise);
+        let status = sandbox::run_with_timeout(&mut command, timeout);
+        runtime.finish();
+        Ok(Some(status?.map(|status| status.success())))
+    } else if exercise.exercise_type == "function_cases" {
+        let work_dir = root.parent().unwrap_or(root);
+        let (compiled, binary) = cases::build_harness(exercise, root, &cases::load_cases(exercise, root)?, work_dir, false, None)?;
+        if !compiled.status.success() {
+            return Ok(None);
+        }
+        let runtime = runtime::prepare(exercise, root)?;
+        let mut command = cases::harness_command(&binary, work_dir);
+        runtime.apply(&mut command, exercise);
+        let status = sandbox::run_with_timeout(&mut command, timeout);
+        runtime.finish();
+        Ok(Some(status?.map(|status| status.success())))
+    } else {
+        let cargo = |args: &[&str]| {
+            let mut command = Command::new("cargo");
+            command.args(args).current_dir(root).envs(&exercise.env);
+            compile::apply_cargo_flags(&mut command, exercise, false);
+            command
+        };
+        let compiled = sandbox::run_with_timeout(&mut cargo(&["test", "--no-run"]), compile_timeout)?;
+        if !compiled.is_some_and(|status| status.success()) {
+            return Ok(None);
+        }
+        runtime::prepare(exercise, root)?.finish();
+        let mut test_args = vec!["test", "--"];
+        test_args.extend(exercise.args.iter().map(String::as_str));
+        let status = sandbox::run_with_timeout(&mut cargo(&test_args), timeout)?;
+        Ok(Some(status.map(|status| status.success())))
+    }
+}
+
+fn run_mutants(exercise: &Exercise, root: &Path) -> io::Result<()> {
+    let mut mutants = Vec::new();
+    for file in mutable_files(exercise, root)? {
+        let full_path = if exercise.exercise_type == "single_file" { root.to_path_buf() } else { root.join(&file) };
+        mutants.extend(generate_mutants(&file, &fs::read_to_string(full_path)?));
+    }
+
+    // 先确认未变异的参考答案能通过测试，并据此确定每个变异体的超时时间
+    let start = Instant::now();
+    if run_tests(exercise, root, Duration::from_secs(600))? != Some(Some(true)) {
+        return Err(io::Error::other(i18n::solution_fails()));
+    }
+    let timeout = (start.elapsed() * 10).max(Duration::from_secs(10));
+
+    println!("{}", i18n::mutants_generated_for(mutants.len(), &exercise.name));
+    let mut survivors = Vec::new();
+    let (mut killed, mut timed_out, mut invalid) = (0, 0, 0);
+    for (index, mutant) in mutants.iter().enumerate() {
+        let path = if exercise.exercise_type == "single_file" { root.to_path_buf() } else { root.join(&mutant.file) };
+        let original = fs::read_to_string(&path)?;
+        let mut mutated = original.clone();
+        mutated.replace_range(mutant.range.clone(), &mutant.replacement);
+        fs::write(&path, &mutated)?;
+        let outcome = match run_tests(exercise, root, timeout) {
+            Ok(None) => MutantOutcome::Invalid,
+            Ok(Some(None)) => MutantOutcome::TimedOut,
+            Ok(Some(Some(true))) => MutantOutcome::Survived,
+            Ok(Some(Some(false))) => MutantOutcome::Killed,
+            Err(e) => {
+                fs::write(&path, &original)?;
+                return Err(e);
+            }
+        };
+        fs::write(&path, &original)?;
+        if interrupt::is_interrupted() {
+            return Err(interrupt::interrupted_error());
+        }
+
+        let label = match outcome {
+            MutantOutcome::Killed => {
+                killed += 1;
+                i18n::mutant_killed()
+            }
+            MutantOutcome::TimedOut => {
+                timed_out += 1;
+                i18n::mutant_timed_out()
+            }
+            MutantOutcome::Invalid => {
+                invalid += 1;
+                i18n::mutant_invalid()
+            }
+            MutantOutcome::Survived => {
+                survivors.push(mutant);
+                format!("\x1b[31m{}\x1b[0m", i18n::mutant_survived())
+            }
+        };
+        println!(
+            "[{}/{}] {}:{}: {} ... {}",
+            index + 1,
+            mutants.len(),
+            mutant.file.display(),
+            mutant.line,
+            mutant.description,
+            label
+        );
+    }
+
+    let valid = mutants.len() - invalid;
+    println!("\n{}", i18n::mutation_summary(&exercise.name));
+    println!("{}", i18n::mutants_generated(mutants.len()));
+    println!("{}", i18n::mutants_killed(killed + timed_out, timed_out));
+    println!("{}", i18n::mutants_survived(survivors.len()));
+    println!("{}", i18n::mutants_invalid(invalid));
+    if valid > 0 {
+        let score = (killed + timed_out) as f64 * 100.0 / valid as f64;
+        println!("{}", i18n::mutation_score(format!("{:.1}", score)));
+    }
+    if !survivors.is_empty() {
+        println!("\n{}", i18n::surviving_mutants());
+        for mutant in survivors {
+            println!("  {}:{}: {}", mutant.file.display(), mutant.line, mutant.description);
+        }
+    }
+    Ok(())
+}
+
+// 对习题的参考答案进行变异测试，报告测试未能发现的变异
+pub fn mutate_exercise(exercise: &Exercise) -> io::Result<()> {
+    let solution = verify::solution_path(exercise)
+        .filter(|path| path.exists())
+        .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, i18n::solution_missing()))?;
+
+    let work_dir = sandbox::create_scratch_dir(&format!("mutate-{}", exercise.name))?;
+    let result = runtime::copy_project(exercise, &solution, &work_dir).and_then(|root| run_mutants(exercise, &root));
+    sandbox::remove_scratch_dir(&work_dir);
+    result
+}
@@ -0,0 +1,146 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{exercise_path, grade_exercise, i18n, interrupt, metadata, Exercise, ExerciseConfig, ExerciseResult, ExerciseStatus, GradeOptions};
+
+// 本地记录每道习题最近一次的评测结果
+const PROGRESS_FILE: &str = ".grader/progress.json";
+
+// 一道习题最近一次评测时的状态，以及当时代码的哈希
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ProgressEntry {
+    pub status: ExerciseStatus,
+    pub score: i32,
+    pub source_hash: String,
+    // 评测时间（Unix 时间戳，秒）
+    pub verified_at: u64,
+}
+
+fn load_progress() -> BTreeMap<String, ProgressEntry> {
+    File::open(PROGRESS_FILE)
+        .ok()
+        .and_then(|file| serde_json::from_reader(file).ok())
+        .unwrap_or_default()
+}
+
+fn save_progress(progress: &BTreeMap<String, ProgressEntry>) -> io::Result<()> {
+    if let Some(parent) = Path::new(PROGRESS_FILE).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = File::create(PROGRESS_FILE)?;
+    serde_json::to_writer_pretty(&mut file, progress)?;
+    writeln!(file)?;
+    Ok(())
+}
+
+// 依次把相对路径与文件内容计入哈希，跳过 Cargo 的 target 构建目录
+fn hash_path(hasher: &mut Sha256, root: &Path, path: &Path) -> io::Result<()> {
+    if path.is_dir() {
+        let mut entries: Vec<_> = fs::read_dir(path)?.collect::<io::Result<_>>()?;
+        entries.sort_by_key(|entry| entry.file_name());
+        for entry in entries {
+            if entry.file_name() != "target" {
+                hash_path(hasher, root, &entry.path())?;
+            }
+        }
+    } else {
+        hasher.update(path.strip_prefix(root).unwrap_or(path).to_string_lossy().as_bytes());
+        hasher.update([0]);
+        hasher.update(fs::read(path)?);
+        hasher.update([0]);
+    }
+    Ok(())
+}
+
+// 计算习题当前代码（单个文件或整个项目目录）的 SHA-256
+fn source_hash(exercise: &Exercise) -> io::Result<String> {
+    let path = exercise_path(exercise);
+    let mut hasher = Sha256::new();
+    hash_path(&mut hasher, &path, &path)?;
+    Ok(hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect())
+}
+
+// 记录习题的评测结果；锁定与被中断的习题没有完成评测，不记录
+pub fn record_result(exercise: &Exercise, result: &ExerciseResult) {
+    if matches!(result.status, ExerciseStatus::Locked | ExerciseStatus::Interrupted) {
+        return;
+    }
+    let Ok(source_hash) = source_hash(exercise) else {
+        return;
+    };
+    let mut progress = load_progress();
+    progress.insert(
+        exercise.name.clone(),
+        ProgressEntry {
+            status: result.status,
+            score: result.score,
+            source_hash,
+            verified_at: SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs()),
+        },
+    );
+    if let Err(e) = save_progress(&progress) {
+        eprintln!("{}", i18n::progress_save_failed(e));
+    }
+}
+
+// 上次评测已通过且之后代码没有改动的习题，以及它们上次的评测记录
+pub fn solved_exercises(exercises: &[Exercise]) -> BTreeMap<String, ProgressEntry> {
+    let mut progress = load_progress();
+    exercises
+        .iter()
+        .filter_map(|exercise| {
+            let entry = progress.remove(&exercise.name)?;
+            let unchanged = source_hash(exercise).is_ok_and(|hash| hash == entry.source_hash);
+            (entry.status == ExerciseStatus::Passed && unchanged).then(|| (exercise.name.clone(), entry))
+        })
+        .collect()
+}
+
+// 按配置顺序跳过已解决的习题，评测下一道；未通过时打开该习题
+pub fn grade_next(config: &ExerciseConfig, options: &GradeOptions) {
+    let exercises: Vec<Exercise> = config
+        .exercises(options.stage)
+        .into_iter()
+        .filter(|exercise| options.tag.is_none_or(|tag| metadata::exercise_info(exercise).has_tag(tag)))
+        .collect();
+    let solved = solved_exercises(&exercises);
+    for exercise in exercises.iter().filter(|exercise| !solved.contains_key(&exercise.name)) {
+        let result = grade_exercise(exercise, &exercise_path(exercise), options, &[]);
+        if interrupt::is_interrupted() {
+            return;
+        }
+        record_result(exercise, &result);
+        if !result.result {
+            open_exercise(exercise);
+            return;
+        }
+    }
+    println!("\x1b[32m{}\x1b[0m", i18n::all_exercises_solved());
+}
+
+// 显示习题的标题与说明，设置了 VISUAL 或 EDITOR 时用编辑器打开习题源码
+fn open_exercise(exercise: &Exercise) {
+    let info = metadata::exercise_info(exercise);
+    let file = metadata::header_file(exercise);
+    println!("\n{}", i18n::next_exercise(&exercise.name, &info.title, file.display()));
+    if let Some(description) = &info.description {
+        println!("{}", description);
+    }
+    let Some(editor) = std::env::var("VISUAL").ok().or_else(|| std::env::var("EDITOR").ok()) else {
+        return;
+    };
+    // 编辑器命令可以带参数，例如 `code --wait`
+    let mut words = editor.split_whitespace();
+    let Some(program) = words.next() else {
+        return;
+    };
+    if let Err(e) = Command::new(program).args(words).arg(&file).status() {
+        eprintln!("{}", i18n::editor_failed(program, e));
+    }
+}
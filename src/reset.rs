@@ -0,0 +1,70 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{exercise_path, i18n, sandbox, Exercise};
+
+// 习题初始版本保存在仓库根目录的 pristine 目录下，目录结构与 exercises 相同
+const PRISTINE_DIR: &str = "./pristine";
+const BACKUP_DIR: &str = ".grader/backups";
+
+pub fn pristine_path(exercise: &Exercise) -> PathBuf {
+    Path::new(PRISTINE_DIR).join(&exercise.path)
+}
+
+fn confirm_reset(exercise: &Exercise) -> bool {
+    print!("{}", i18n::confirm_reset(&exercise.name));
+    let _ = io::stdout().flush();
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input).is_err() {
+        return false;
+    }
+    matches!(input.trim().to_lowercase().as_str(), "y" | "yes")
+}
+
+// 复制文件或目录，目录会先被整体清空再复制
+fn replace_with(src: &Path, dst: &Path) -> io::Result<()> {
+    if src.is_dir() {
+        if dst.exists() {
+            fs::remove_dir_all(dst)?;
+        }
+        sandbox::copy_dir(src, dst)
+    } else {
+        if let Some(parent) = dst.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(src, dst).map(|_| ())
+    }
+}
+
+// 备份学生当前版本后，将习题恢复为初始版本
+pub fn reset_exercise(exercise: &Exercise) -> io::Result<()> {
+    let pristine = pristine_path(exercise);
+    if !pristine.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::NotFound,
+            i18n::pristine_not_found(pristine.display()),
+        ));
+    }
+
+    if !confirm_reset(exercise) {
+        println!("{}", i18n::reset_cancelled());
+        return Ok(());
+    }
+
+    let current = exercise_path(exercise);
+    if current.exists() {
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let file_name = current.file_name().unwrap_or_default();
+        let backup = Path::new(BACKUP_DIR)
+            .join(format!("{}-{}", exercise.name, timestamp))
+            .join(file_name);
+        replace_with(&current, &backup)?;
+        println!("{}", i18n::backed_up(backup.display()));
+    }
+
+    replace_with(&pristine, &current)?;
+    println!("{}", i18n::reset_done(&exercise.name));
+    Ok(())
+}
@@ -0,0 +1,127 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::{exercise_path, i18n, sandbox, source, Exercise};
+
+// 测试程序的运行目录：配置了 fixtures 或 working_dir 的单文件与 function_cases 习题
+// 在独立的工作目录中运行测试，夹具文件会先复制到该目录
+pub struct Runtime {
+    work_dir: Option<PathBuf>,
+    cwd: Option<PathBuf>,
+}
+
+// 夹具文件所在的目录：单文件习题为文件所在目录，其余为习题目录
+fn fixture_root(exercise: &Exercise) -> PathBuf {
+    let path = exercise_path(exercise);
+    match (exercise.exercise_type.as_str(), path.parent()) {
+        ("single_file", Some(parent)) => parent.to_path_buf(),
+        _ => path,
+    }
+}
+
+// 夹具文件与 working_dir 都必须是习题目录内部的相对路径
+fn relative_path(value: &str) -> io::Result<&Path> {
+    let path = Path::new(value);
+    if path.is_absolute() || path.components().any(|c| matches!(c, std::path::Component::ParentDir)) {
+        return Err(io::Error::new(io::ErrorKind::InvalidInput, i18n::path_outside_exercise(value)));
+    }
+    Ok(path)
+}
+
+fn copy_fixtures(exercise: &Exercise, dest: &Path) -> io::Result<()> {
+    let root = fixture_root(exercise);
+    for fixture in &exercise.fixtures {
+        let relative = relative_path(fixture)?;
+        let (from, to) = (root.join(relative), dest.join(relative));
+        if let Some(parent) = to.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        if from.is_dir() {
+            sandbox::copy_dir(&from, &to)?;
+        } else {
+            fs::copy(&from, &to)?;
+        }
+    }
+    Ok(())
+}
+
+// Cargo 项目的 src/tests.rs 通过 `#[path = "../../xxx.rs"]` 引入的共用测试辅助文件（例如 time_budget.rs），
+// 这些文件与项目目录位于同一目录下；返回相对于该目录的路径
+pub fn shared_test_files(project: &Path) -> Vec<PathBuf> {
+    let Ok(tests) = fs::read_to_string(project.join("src").join("tests.rs")) else {
+        return Vec::new();
+    };
+    source::path_attributes(&tests)
+        .into_iter()
+        .filter_map(|path| Path::new(path).strip_prefix("../..").ok())
+        .filter_map(|path| relative_path(path.to_str()?).ok())
+        .map(Path::to_path_buf)
+        .collect()
+}
+
+// 把 src 处的习题代码复制到工作目录中，Cargo 项目的共用测试辅助文件从习题目录旁复制到副本旁
+pub fn copy_project(exercise: &Exercise, src: &Path, work_dir: &Path) -> io::Result<PathBuf> {
+    let copy = sandbox::copy_to_scratch(src, work_dir)?;
+    if exercise.exercise_type == "cargo_project" {
+        let root = exercise_path(exercise);
+        let shared_dir = root.parent().unwrap_or(Path::new("."));
+        for file in shared_test_files(&copy) {
+            fs::copy(shared_dir.join(&file), work_dir.join(&file))?;
+        }
+    }
+    Ok(copy)
+}
+
+// 为评测位于 path 的习题代码准备运行目录
+// Cargo 项目的测试由 cargo 在项目根目录运行，评测副本中缺少的夹具文件会复制到副本的根目录
+pub fn prepare(exercise: &Exercise, path: &Path) -> io::Result<Runtime> {
+    if exercise.exercise_type == "cargo_project" {
+        let is_copy = fs::canonicalize(path)? != fs::canonicalize(exercise_path(exercise))?;
+        if is_copy {
+            copy_fixtures(exercise, path)?;
+        }
+        return Ok(Runtime { work_dir: None, cwd: None });
+    }
+    if exercise.fixtures.is_empty() && exercise.working_dir.is_none() {
+        return Ok(Runtime { work_dir: None, cwd: None });
+    }
+    let work_dir = sandbox::create_scratch_dir(&format!("run-{}", exercise.name))?;
+    let runtime = copy_fixtures(exercise, &work_dir).and_then(|_| {
+        let cwd = match &exercise.working_dir {
+            Some(dir) => work_dir.join(relative_path(dir)?),
+            None => work_dir.clone(),
+        };
+        fs::create_dir_all(&cwd)?;
+        Ok(cwd)
+    });
+    match runtime {
+        Ok(cwd) => Ok(Runtime { work_dir: Some(work_dir), cwd: Some(cwd) }),
+        Err(e) => {
+            sandbox::remove_scratch_dir(&work_dir);
+            Err(e)
+        }
+    }
+}
+
+impl Runtime {
+    // 设置测试程序的环境变量与工作目录
+    pub fn apply(&self, command: &mut Command, exercise: &Exercise) {
+        command.envs(&exercise.env);
+        if let Some(cwd) = &self.cwd {
+            command.current_dir(cwd);
+        }
+    }
+
+    pub fn finish(self) {
+        if let Some(work_dir) = &self.work_dir {
+            sandbox::remove_scratch_dir(work_dir);
+        }
+    }
+}
+
+// 工作目录改变后相对路径会失效，测试程序使用绝对路径启动
+pub fn absolute(path: &Path) -> PathBuf {
+    fs::canonicalize(path).unwrap_or_else(|_| path.to_path_buf())
+}
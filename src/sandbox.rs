@@ -0,0 +1,76 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::time::{Duration, Instant};
+
+use crate::{i18n, interrupt};
+
+// 在系统临时目录下为习题创建独立的工作目录
+pub fn create_scratch_dir(label: &str) -> io::Result<PathBuf> {
+    let dir = std::env::temp_dir().join(format!("cargotest-{}-{}", std::process::id(), label));
+    if dir.exists() {
+        fs::remove_dir_all(&dir)?;
+    }
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+// 递归复制目录，跳过 Cargo 的 target 构建目录
+pub fn copy_dir(src: &Path, dst: &Path) -> io::Result<()> {
+    fs::create_dir_all(dst)?;
+    for entry in fs::read_dir(src)? {
+        let entry = entry?;
+        let from = entry.path();
+        let to = dst.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            if entry.file_name() == "target" {
+                continue;
+            }
+            copy_dir(&from, &to)?;
+        } else {
+            fs::copy(&from, &to)?;
+        }
+    }
+    Ok(())
+}
+
+// 将 src 处的代码（单个文件或目录）复制到工作目录中，返回副本的位置
+pub fn copy_to_scratch(src: &Path, work_dir: &Path) -> io::Result<PathBuf> {
+    let copy = work_dir.join(src.file_name().unwrap_or_default());
+    if src.is_dir() {
+        copy_dir(src, &copy)?;
+    } else {
+        fs::copy(src, &copy)?;
+    }
+    Ok(copy)
+}
+
+// 删除工作目录，失败时仅打印提示
+pub fn remove_scratch_dir(dir: &Path) {
+    if let Err(e) = fs::remove_dir_all(dir) {
+        eprintln!("{}", i18n::remove_scratch_failed(dir.display(), e));
+    }
+}
+
+// 在独立进程组中运行命令，超时后结束整个进程组（包括 cargo 启动的测试进程）
+// 返回 None 表示超时
+pub fn run_with_timeout(command: &mut Command, timeout: Duration) -> io::Result<Option<ExitStatus>> {
+    let mut child = interrupt::isolate(command)
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        // 评测被中断时与超时一样结束子进程
+        if Instant::now() >= deadline || interrupt::is_interrupted() {
+            interrupt::kill_process_group(&mut child);
+            let _ = child.wait();
+            return Ok(None);
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
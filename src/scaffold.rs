@@ -0,0 +1,167 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::{i18n, integrity, reset, sandbox, Exercise, ExerciseConfig};
+
+const SINGLE_FILE_TEMPLATE: &str = r#"/*
+    {name}
+    Describe the problem here.
+    Tags:
+    Difficulty:
+*/
+
+pub fn solve(_input: i32) -> i32 {
+    // TODO: implement
+    todo!()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 定义测试用例和预期结果
+    const TEST_CASES: &[(i32, i32)] = &[
+        (0, 0),
+    ];
+
+    #[test]
+    fn test_solve() {
+        for (input, expected) in TEST_CASES {
+            let result = solve(*input);
+            println!("solve({}) = {}", input, result);
+            assert_eq!(result, *expected);
+        }
+    }
+}
+"#;
+
+const CARGO_TOML_TEMPLATE: &str = r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+
+[[test]]
+name = "tests"
+path = "src/tests.rs"
+"#;
+
+const CARGO_MAIN_TEMPLATE: &str = r#"mod solution;
+
+fn main() {
+    let input = 0;
+    println!("solve({}) = {}", input, solution::solve(input));
+}
+"#;
+
+const CARGO_SOLUTION_TEMPLATE: &str = r#"pub fn solve(_input: i32) -> i32 {
+    // TODO: implement
+    todo!()
+}
+"#;
+
+const CARGO_TESTS_TEMPLATE: &str = r#"mod solution;
+
+#[cfg(test)]
+mod tests {
+    use super::solution::solve;
+
+    // 定义测试用例和预期结果
+    const TEST_CASES: &[(i32, i32)] = &[
+        (0, 0),
+    ];
+
+    // 定义一个测试函数来验证每个测试用例，按整数统计通过的用例数以免浮点累加产生误差
+    #[test]
+    fn test_solve() {
+        let mut passed = 0;
+        for (input, expected) in TEST_CASES {
+            let result = solve(*input);
+            if result == *expected {
+                passed += 1;
+            } else {
+                println!("Test case {} failed. Expected {}, got {}", input, expected, result);
+            }
+        }
+        println!("Total score: {:.2}", 100.0 * passed as f64 / TEST_CASES.len() as f64);
+        assert_eq!(passed, TEST_CASES.len());
+    }
+}
+"#;
+
+fn invalid_input(message: String) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+// 生成新习题骨架并登记到配置对应难度的数组中
+pub fn create_exercise(
+    config: &mut ExerciseConfig,
+    stage: Option<&str>,
+    exercise_type: &str,
+    difficulty: &str,
+    name: &str,
+) -> io::Result<()> {
+    let name = name.strip_suffix(".rs").unwrap_or(name);
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+        return Err(invalid_input(i18n::invalid_exercise_name(name)));
+    }
+
+    let (entry_name, relative_path) = match exercise_type {
+        "single_file" => (format!("{}.rs", name), format!("{}/{}.rs", difficulty, name)),
+        "cargo_project" => (name.to_string(), format!("{}/{}", difficulty, name)),
+        _ => return Err(invalid_input(i18n::unknown_exercise_type(exercise_type))),
+    };
+
+    if config.find_exercise(&entry_name).is_some() {
+        return Err(invalid_input(i18n::exercise_exists(&entry_name)));
+    }
+    let category = config
+        .category_mut(stage, difficulty)
+        .ok_or_else(|| invalid_input(i18n::unknown_difficulty(difficulty)))?;
+
+    let path = Path::new("./exercises").join(&relative_path);
+    if path.exists() {
+        return Err(invalid_input(i18n::path_exists(path.display())));
+    }
+
+    if exercise_type == "single_file" {
+        fs::create_dir_all(path.parent().unwrap_or(Path::new(".")))?;
+        fs::write(&path, SINGLE_FILE_TEMPLATE.replace("{name}", name))?;
+    } else {
+        fs::create_dir_all(path.join("src"))?;
+        fs::write(path.join("Cargo.toml"), CARGO_TOML_TEMPLATE.replace("{name}", name))?;
+        fs::write(path.join("src").join("main.rs"), CARGO_MAIN_TEMPLATE)?;
+        fs::write(path.join("src").join("solution.rs"), CARGO_SOLUTION_TEMPLATE)?;
+        fs::write(path.join("src").join("tests.rs"), CARGO_TESTS_TEMPLATE)?;
+    }
+    println!("{}", i18n::created(path.display()));
+
+    // 分类设置了默认分值时沿用默认值，否则沿用同难度已有习题的分值
+    let score = match category.score {
+        Some(_) => None,
+        None => Some(category.exercises.first().and_then(|exercise| exercise.score).unwrap_or(1)),
+    };
+    let test_hash = integrity::compute_test_hash(exercise_type, &path).ok();
+    let exercise = Exercise {
+        name: entry_name,
+        path: relative_path,
+        exercise_type: exercise_type.to_string(),
+        score,
+        test_hash,
+        ..Default::default()
+    };
+
+    // 生成的骨架即为习题的初始版本
+    let pristine = reset::pristine_path(&exercise);
+    if exercise_type == "single_file" {
+        fs::create_dir_all(pristine.parent().unwrap_or(Path::new(".")))?;
+        fs::copy(&path, &pristine)?;
+    } else {
+        sandbox::copy_dir(&path, &pristine)?;
+    }
+
+    category.exercises.push(exercise);
+    Ok(())
+}
@@ -0,0 +1,307 @@
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use crate::{exercise_path, grade_exercise, i18n, metadata, runtime, sandbox, Exercise, ExerciseConfig, GradeOptions};
+
+// 请求体大小上限，避免一次提交占用过多内存
+const MAX_BODY_BYTES: usize = 1024 * 1024;
+
+// 读取请求的超时时间，防止连接一直占用处理线程
+const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+// 一次提交：习题名称以及要覆盖的源码文件
+// 单文件习题的文件名为习题文件名，Cargo 项目为相对于项目根目录的路径（如 `src/solution.rs`）
+#[derive(Deserialize)]
+struct Submission {
+    exercise: String,
+    files: BTreeMap<String, String>,
+}
+
+#[derive(Serialize)]
+struct ExerciseSummary {
+    name: String,
+    title: String,
+    #[serde(rename = "type")]
+    exercise_type: String,
+}
+
+struct Response {
+    status: u16,
+    body: String,
+}
+
+impl Response {
+    fn json(status: u16, value: &impl Serialize) -> Self {
+        let body = serde_json::to_string_pretty(value).unwrap_or_default();
+        Response { status, body }
+    }
+
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        let mut body = BTreeMap::new();
+        body.insert("error", message.into());
+        Response::json(status, &body)
+    }
+}
+
+// 排队等待评测的提交，评测结果通过 reply 返回给对应的连接
+struct Job {
+    exercise: Exercise,
+    files: BTreeMap<String, String>,
+    reply: mpsc::Sender<Response>,
+}
+
+struct Request {
+    method: String,
+    path: String,
+    // 浏览器发出的跨域请求带有 Origin 头
+    origin: Option<String>,
+    content_type: Option<String>,
+    body: Vec<u8>,
+}
+
+// 在本机指定端口上提供评测服务，提交按到达顺序逐个评测
+// 只接受来自 allowed_origins 中页面的跨域请求，其他网页无法向服务提交代码
+pub fn serve(config: &ExerciseConfig, options: &GradeOptions, port: u16, allowed_origins: &[&str]) -> io::Result<()> {
+    let listener = TcpListener::bind(("127.0.0.1", port))?;
+    println!("{}", i18n::server_listening(listener.local_addr()?));
+    let (queue, jobs) = mpsc::channel::<Job>();
+    // 尚未评测完成的提交数量（包括正在评测的一个）
+    let waiting = AtomicUsize::new(0);
+
+    thread::scope(|scope| {
+        scope.spawn(|| {
+            for (id, job) in jobs.into_iter().enumerate() {
+                let response = grade_submission(&job.exercise, &job.files, options, id);
+                waiting.fetch_sub(1, Ordering::SeqCst);
+                let _ = job.reply.send(response);
+            }
+        });
+
+        for stream in listener.incoming() {
+            let Ok(stream) = stream else {
+                continue;
+            };
+            let queue = queue.clone();
+            let waiting = &waiting;
+            scope.spawn(move || {
+                if let Err(e) = handle_connection(stream, config, allowed_origins, &queue, waiting) {
+                    eprintln!("{}", i18n::connection_failed(e));
+                }
+            });
+        }
+    });
+    Ok(())
+}
+
+fn handle_connection(
+    mut stream: TcpStream,
+    config: &ExerciseConfig,
+    allowed_origins: &[&str],
+    queue: &mpsc::Sender<Job>,
+    waiting: &AtomicUsize,
+) -> io::Result<()> {
+    stream.set_read_timeout(Some(READ_TIMEOUT))?;
+    let (response, origin) = match read_request(&mut stream)? {
+        Err(response) => (response, None),
+        Ok(request) => {
+            let origin = request.origin.clone().filter(|origin| allowed_origins.contains(&origin.as_str()));
+            (route(request, config, allowed_origins, queue, waiting), origin)
+        }
+    };
+    write_response(&mut stream, &response, origin.as_deref())
+}
+
+fn route(
+    request: Request,
+    config: &ExerciseConfig,
+    allowed_origins: &[&str],
+    queue: &mpsc::Sender<Job>,
+    waiting: &AtomicUsize,
+) -> Response {
+    // 不带 Origin 的请求来自命令行工具等非浏览器客户端
+    if let Some(origin) = &request.origin {
+        if !allowed_origins.contains(&origin.as_str()) {
+            return Response::error(403, i18n::origin_not_allowed(origin));
+        }
+    }
+    match (request.method.as_str(), request.path.as_str()) {
+        // 浏览器跨域提交前的预检请求
+        ("OPTIONS", _) => Response { status: 204, body: String::new() },
+        ("GET", "/exercises") => {
+            let exercises: Vec<ExerciseSummary> = config
+                .exercises(None)
+                .iter()
+                .map(|exercise| ExerciseSummary {
+                    name: exercise.name.clone(),
+                    title: metadata::exercise_info(exercise).title,
+                    exercise_type: exercise.exercise_type.clone(),
+                })
+                .collect();
+            Response::json(200, &exercises)
+        }
+        ("POST", "/grade") => {
+            // 要求 JSON 请求体，使浏览器的跨域提交必须先经过预检
+            let is_json = request.content_type.as_deref().is_some_and(|value| {
+                value.split(';').next().unwrap_or_default().trim().eq_ignore_ascii_case("application/json")
+            });
+            if !is_json {
+                return Response::error(415, i18n::json_required());
+            }
+            let submission: Submission = match serde_json::from_slice(&request.body) {
+                Ok(submission) => submission,
+                Err(e) => return Response::error(400, i18n::invalid_submission(e)),
+            };
+            let Some(exercise) = config.find_exercise(&submission.exercise) else {
+                return Response::error(404, i18n::unknown_exercise(&submission.exercise));
+            };
+            let (reply, result) = mpsc::channel();
+            let position = waiting.fetch_add(1, Ordering::SeqCst) + 1;
+            println!("{}", i18n::submission_queued(&exercise.name, position));
+            let job = Job { exercise, files: submission.files, reply };
+            if queue.send(job).is_err() {
+                return Response::error(503, i18n::queue_not_running());
+            }
+            result.recv().unwrap_or_else(|_| Response::error(500, i18n::submission_grading_failed()))
+        }
+        (_, "/exercises" | "/grade") => Response::error(405, i18n::method_not_allowed()),
+        _ => Response::error(404, i18n::route_not_found()),
+    }
+}
+
+// 读取一个 HTTP/1.1 请求，格式不正确时返回相应的错误响应
+fn read_request(stream: &mut TcpStream) -> io::Result<Result<Request, Response>> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target)) = (parts.next(), parts.next()) else {
+        return Ok(Err(Response::error(400, i18n::malformed_request())));
+    };
+    let method = method.to_string();
+    let path = target.split('?').next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    let mut origin = None;
+    let mut content_type = None;
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((name, value)) = header.split_once(':') {
+            let (name, value) = (name.trim(), value.trim());
+            if name.eq_ignore_ascii_case("content-length") {
+                match value.parse() {
+                    Ok(length) => content_length = length,
+                    Err(_) => return Ok(Err(Response::error(400, i18n::invalid_content_length()))),
+                }
+            } else if name.eq_ignore_ascii_case("origin") {
+                origin = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case("content-type") {
+                content_type = Some(value.to_string());
+            }
+        }
+    }
+    if content_length > MAX_BODY_BYTES {
+        return Ok(Err(Response::error(413, i18n::submission_too_large())));
+    }
+    let mut body = vec![0; content_length];
+    reader.read_exact(&mut body)?;
+    Ok(Ok(Request { method, path, origin, content_type, body }))
+}
+
+// origin 为允许的跨域来源时才返回 CORS 头
+fn write_response(stream: &mut TcpStream, response: &Response, origin: Option<&str>) -> io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        204 => "No Content",
+        400 => "Bad Request",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        413 => "Payload Too Large",
+        415 => "Unsupported Media Type",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    };
+    let cors = match origin {
+        Some(origin) => format!(
+            "Access-Control-Allow-Origin: {}\r\n\
+             Access-Control-Allow-Methods: GET, POST, OPTIONS\r\n\
+             Access-Control-Allow-Headers: Content-Type\r\n\
+             Vary: Origin\r\n",
+            origin
+        ),
+        None => String::new(),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\n\
+         Content-Type: application/json; charset=utf-8\r\n\
+         Content-Length: {}\r\n\
+         {}\
+         Connection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        response.body.len(),
+        cors,
+        response.body
+    )?;
+    stream.flush()
+}
+
+// 提交的文件路径必须是副本内部的相对路径
+// Cargo 项目只接受 src 目录下的 .rs 文件，Cargo.toml、build.rs 等会在编译时执行代码的文件不能被覆盖
+fn submitted_file_path(exercise: &Exercise, copy: &Path, name: &str) -> Result<PathBuf, String> {
+    let relative = Path::new(name);
+    if relative.as_os_str().is_empty() || !relative.components().all(|c| matches!(c, Component::Normal(_))) {
+        return Err(i18n::invalid_file_path(name));
+    }
+    if exercise.exercise_type == "single_file" {
+        if copy.file_name() != Some(relative.as_os_str()) {
+            return Err(i18n::single_file_only(&exercise.name, copy.file_name().unwrap_or_default().to_string_lossy()));
+        }
+        Ok(copy.to_path_buf())
+    } else if exercise.exercise_type == "cargo_project"
+        && !(relative.starts_with("src") && relative.extension().is_some_and(|ext| ext == "rs"))
+    {
+        Err(i18n::src_files_only(&exercise.name))
+    } else {
+        Ok(copy.join(relative))
+    }
+}
+
+// 在习题的独立副本中写入提交的文件并评测，学生目录中的代码不受影响
+fn grade_submission(exercise: &Exercise, files: &BTreeMap<String, String>, options: &GradeOptions, id: usize) -> Response {
+    let work_dir = match sandbox::create_scratch_dir(&format!("serve-{}-{}", id, exercise.name)) {
+        Ok(dir) => dir,
+        Err(e) => return Response::error(500, i18n::sandbox_prepare_failed(e)),
+    };
+    let response = runtime::copy_project(exercise, &exercise_path(exercise), &work_dir)
+        .map_err(|e| Response::error(500, i18n::exercise_copy_failed(e)))
+        .and_then(|copy| {
+            for (name, content) in files {
+                let path = submitted_file_path(exercise, &copy, name).map_err(|e| Response::error(400, e))?;
+                if let Some(parent) = path.parent() {
+                    fs::create_dir_all(parent).map_err(|e| Response::error(500, e.to_string()))?;
+                }
+                fs::write(&path, content).map_err(|e| Response::error(500, e.to_string()))?;
+            }
+            let result = grade_exercise(exercise, &copy, options, &[]);
+            Ok(Response::json(200, &result))
+        });
+    sandbox::remove_scratch_dir(&work_dir);
+    response.unwrap_or_else(|response| response)
+}
@@ -0,0 +1,288 @@
+use std::ops::Range;
+
+// 跳过字符串、字符字面量与注释后返回下一个需要关注的位置
+// 返回 None 表示 pos 处是普通代码字符
+fn skip_non_code(bytes: &[u8], pos: usize) -> Option<usize> {
+    let rest = &bytes[pos..];
+    if rest.starts_with(b"//") {
+        let end = rest.iter().position(|&b| b == b'\n').map_or(bytes.len(), |i| pos + i);
+        return Some(end);
+    }
+    if rest.starts_with(b"/*") {
+        // 块注释允许嵌套
+        let mut depth = 0;
+        let mut i = pos;
+        while i < bytes.len() {
+            if bytes[i..].starts_with(b"/*") {
+                depth += 1;
+                i += 2;
+            } else if bytes[i..].starts_with(b"*/") {
+                depth -= 1;
+                i += 2;
+                if depth == 0 {
+                    return Some(i);
+                }
+            } else {
+                i += 1;
+            }
+        }
+        return Some(bytes.len());
+    }
+    if rest.starts_with(b"r\"") || rest.starts_with(b"r#") || rest.starts_with(b"br\"") || rest.starts_with(b"br#") {
+        let start = if rest[0] == b'b' { pos + 2 } else { pos + 1 };
+        let hashes = bytes[start..].iter().take_while(|&&b| b == b'#').count();
+        if bytes.get(start + hashes) == Some(&b'"') {
+            let mut closing = vec![b'"'];
+            closing.extend(std::iter::repeat_n(b'#', hashes));
+            let body = start + hashes + 1;
+            let end = bytes[body..]
+                .windows(closing.len())
+                .position(|w| w == closing.as_slice())
+                .map_or(bytes.len(), |i| body + i + closing.len());
+            return Some(end);
+        }
+        return None;
+    }
+    match rest[0] {
+        b'"' => {
+            let mut i = pos + 1;
+            while i < bytes.len() {
+                match bytes[i] {
+                    b'\\' => i += 2,
+                    b'"' => return Some(i + 1),
+                    _ => i += 1,
+                }
+            }
+            Some(bytes.len())
+        }
+        b'\'' => {
+            // 区分字符字面量与生命周期标注
+            if rest.get(1) == Some(&b'\\') {
+                let end = rest[2..].iter().position(|&b| b == b'\'').map_or(bytes.len(), |i| pos + 2 + i + 1);
+                return Some(end);
+            }
+            let ch_len = match rest.get(1) {
+                Some(&b) if b >= 0xF0 => 4,
+                Some(&b) if b >= 0xE0 => 3,
+                Some(&b) if b >= 0xC0 => 2,
+                _ => 1,
+            };
+            if rest.get(1 + ch_len) == Some(&b'\'') {
+                Some(pos + ch_len + 2)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+// 将源码中注释与字面量以外的代码片段依次交给回调处理
+// 回调返回 Some 时立即停止遍历
+pub fn scan_code<T>(source: &str, start: usize, mut f: impl FnMut(usize, u8) -> Option<T>) -> Option<T> {
+    let bytes = source.as_bytes();
+    let mut i = start;
+    while i < bytes.len() {
+        if let Some(next) = skip_non_code(bytes, i) {
+            i = next;
+            continue;
+        }
+        if let Some(result) = f(i, bytes[i]) {
+            return Some(result);
+        }
+        i += 1;
+    }
+    None
+}
+
+// 从 start 处的 '{' 开始匹配到对应的 '}'，返回 '}' 之后的位置
+pub fn matching_brace(source: &str, start: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    scan_code(source, start, |i, b| {
+        match b {
+            b'{' => depth += 1,
+            b'}' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 {
+                    return Some(i + 1);
+                }
+            }
+            _ => {}
+        }
+        None
+    })
+}
+
+// 定位单文件习题中的 `#[cfg(test)] mod xxx { ... }` 测试模块
+// 返回范围从模块的第一个外部属性开始（包括写在 #[cfg(test)] 之前的属性），到模块的右花括号结束
+pub fn find_test_module(source: &str) -> Option<Range<usize>> {
+    let attr = "#[cfg(test)]";
+    let bytes = source.as_bytes();
+    // 当前条目第一个外部属性的位置，遇到条目或代码块的边界时清空
+    let mut item_start = None;
+    // 方括号与圆括号的嵌套深度，其中的 `;` 不是条目的边界
+    let mut nesting = 0usize;
+    let mut from = 0;
+    loop {
+        let (start, attr_pos) = scan_code(source, from, |i, b| {
+            match b {
+                b'[' | b'(' => nesting += 1,
+                b']' | b')' => nesting = nesting.saturating_sub(1),
+                b'{' | b'}' => item_start = None,
+                b';' if nesting == 0 => item_start = None,
+                b'#' if nesting == 0 && bytes.get(i + 1) != Some(&b'!') => {
+                    let start = *item_start.get_or_insert(i);
+                    if bytes[i..].starts_with(attr.as_bytes()) {
+                        return Some((start, i));
+                    }
+                }
+                _ => {}
+            }
+            None
+        })?;
+        let after_attr = attr_pos + attr.len();
+        let rest = source[after_attr..].trim_start();
+        let rest = rest.strip_prefix("pub ").map_or(rest, str::trim_start);
+        if rest.starts_with("mod ") {
+            let open = scan_code(source, after_attr, |i, b| (b == b'{').then_some(i))?;
+            let end = matching_brace(source, open)?;
+            return Some(start..end);
+        }
+        from = after_attr;
+    }
+}
+
+// 标记源码中每个字节是否为代码（注释与字面量以外的部分）
+pub fn code_mask(source: &str) -> Vec<bool> {
+    let mut is_code = vec![false; source.len()];
+    scan_code::<()>(source, 0, |i, _| {
+        is_code[i] = true;
+        None
+    });
+    is_code
+}
+
+// 在 code_mask 的基础上去掉测试模块，测试模块之后的学生代码仍然标记为代码
+pub fn student_code_mask(source: &str) -> Vec<bool> {
+    let mut is_code = code_mask(source);
+    if let Some(range) = find_test_module(source) {
+        is_code[range].fill(false);
+    }
+    is_code
+}
+
+// 列出源码中 `#[path = "..."]` 属性指定的文件路径
+pub fn path_attributes(source: &str) -> Vec<&str> {
+    let attr = b"#[path";
+    let mut paths = Vec::new();
+    scan_code::<()>(source, 0, |i, _| {
+        if source.as_bytes()[i..].starts_with(attr) {
+            let rest = source[i + attr.len()..].trim_start();
+            let value = rest.strip_prefix('=').map(str::trim_start).and_then(|rest| rest.strip_prefix('"'));
+            if let Some((path, _)) = value.and_then(|value| value.split_once('"')) {
+                paths.push(path);
+            }
+        }
+        None
+    });
+    paths
+}
+
+// 统计源码中 #[test] 测试函数的个数
+pub fn count_tests(source: &str) -> usize {
+    let attr = b"#[test]";
+    let mut count = 0;
+    scan_code::<()>(source, 0, |i, _| {
+        if source.as_bytes()[i..].starts_with(attr) {
+            count += 1;
+        }
+        None
+    });
+    count
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_module(source: &str) -> Option<&str> {
+        find_test_module(source).map(|range| &source[range])
+    }
+
+    #[test]
+    fn finds_module_after_code() {
+        let source = "fn f() {}\n\n#[cfg(test)]\nmod tests {\n    #[test]\n    fn t() {}\n}\n";
+        assert_eq!(test_module(source), Some("#[cfg(test)]\nmod tests {\n    #[test]\n    fn t() {}\n}"));
+    }
+
+    #[test]
+    fn accepts_pub_mod() {
+        let source = "#[cfg(test)]\npub mod tests {\n}\n";
+        assert_eq!(test_module(source), Some("#[cfg(test)]\npub mod tests {\n}"));
+    }
+
+    #[test]
+    fn includes_preceding_attributes() {
+        let source = "fn f() {}\n#[cfg(any())]\n#[allow(unused)]\n#[cfg(test)]\nmod tests {}\n";
+        assert_eq!(test_module(source), Some("#[cfg(any())]\n#[allow(unused)]\n#[cfg(test)]\nmod tests {}"));
+        let source = "const A: [u8; 2] = [1; 2];\n#[cfg(any())] /* x */ #[cfg(test)]\nmod tests {}\n";
+        assert_eq!(test_module(source), Some("#[cfg(any())] /* x */ #[cfg(test)]\nmod tests {}"));
+    }
+
+    #[test]
+    fn excludes_attributes_of_previous_items() {
+        let source = "#[derive(Debug)]\nstruct S;\n#[cfg(test)]\nmod tests {}\n";
+        assert_eq!(test_module(source), Some("#[cfg(test)]\nmod tests {}"));
+        let source = "#![allow(unused)]\n#[cfg(test)]\nmod tests {}\n";
+        assert_eq!(test_module(source), Some("#[cfg(test)]\nmod tests {}"));
+    }
+
+    #[test]
+    fn skips_cfg_test_on_other_items() {
+        let source = "#[cfg(test)]\nuse std::fmt;\n#[cfg(test)]\nfn helper() {}\n#[cfg(test)]\nmod tests {}\n";
+        assert_eq!(test_module(source), Some("#[cfg(test)]\nmod tests {}"));
+    }
+
+    #[test]
+    fn ignores_raw_strings() {
+        let source = "const S: &str = r#\"#[cfg(test)] mod fake { \"# ;\n#[cfg(test)]\nmod tests {\n    const T: &str = r##\"}\"##;\n}\n";
+        assert_eq!(test_module(source), Some("#[cfg(test)]\nmod tests {\n    const T: &str = r##\"}\"##;\n}"));
+    }
+
+    #[test]
+    fn ignores_nested_comments() {
+        let source = "/* outer /* inner */ #[cfg(test)] mod fake {} */\n#[cfg(test)]\nmod tests {\n    /* } /* } */ } */\n}\n";
+        assert_eq!(test_module(source), Some("#[cfg(test)]\nmod tests {\n    /* } /* } */ } */\n}"));
+    }
+
+    #[test]
+    fn distinguishes_lifetimes_from_char_literals() {
+        let source = "fn f<'a>(s: &'a str) -> char { if s.is_empty() { '{' } else { '\\'' } }\n#[cfg(test)]\nmod tests {\n    const C: char = '}';\n}\n";
+        assert_eq!(test_module(source), Some("#[cfg(test)]\nmod tests {\n    const C: char = '}';\n}"));
+    }
+
+    #[test]
+    fn returns_none_without_test_module() {
+        assert_eq!(test_module("fn main() {}\n// #[cfg(test)] mod tests {}\n"), None);
+    }
+
+    #[test]
+    fn student_code_excludes_only_test_module() {
+        let source = "fn a() {}\n#[cfg(test)]\nmod tests {}\nfn b() {}\n";
+        let is_code = student_code_mask(source);
+        let code: String = source.char_indices().filter(|&(i, _)| is_code[i]).map(|(_, c)| c).collect();
+        assert_eq!(code, "fn a() {}\n\nfn b() {}\n");
+    }
+
+    #[test]
+    fn lists_path_attributes() {
+        let source = "#[path = \"../../time_budget.rs\"]\nmod time_budget;\n// #[path = \"x.rs\"]\n#[path=\"util.rs\"] mod util;\n";
+        assert_eq!(path_attributes(source), ["../../time_budget.rs", "util.rs"]);
+    }
+
+    #[test]
+    fn counts_tests_outside_comments_and_strings() {
+        let source = "#[test]\nfn a() {}\n// #[test]\nconst S: &str = \"#[test]\";\n#[test]\n#[should_panic]\nfn b() {}\n";
+        assert_eq!(count_tests(source), 2);
+    }
+}
@@ -0,0 +1,142 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::{cases, compile, complexity, constraints, evaluate_exercise_at, hidden, i18n, interrupt, memory, reset, runtime, sandbox, Exercise, Metrics};
+
+// 参考答案不放在学生目录中，默认位于仓库根目录的 solutions 目录，可通过环境变量覆盖
+const SOLUTIONS_DIR_ENV: &str = "SOLUTIONS_DIR";
+const DEFAULT_SOLUTIONS_DIR: &str = "./solutions";
+
+pub fn solution_path(exercise: &Exercise) -> Option<PathBuf> {
+    let relative = exercise.solution.as_ref()?;
+    let root = std::env::var_os(SOLUTIONS_DIR_ENV).map_or_else(|| PathBuf::from(DEFAULT_SOLUTIONS_DIR), PathBuf::from);
+    Some(root.join(relative))
+}
+
+// 在副本中评测 src 处的代码，包括隐藏测试
+fn grade_copy(exercise: &Exercise, src: &Path, label: &str) -> io::Result<bool> {
+    let work_dir = sandbox::create_scratch_dir(&format!("{}-{}", label, exercise.name))?;
+    let result = runtime::copy_project(exercise, src, &work_dir).map(|copy| {
+        evaluate_exercise_at(exercise, &copy, 1, &mut Metrics::default(), None) && hidden::evaluate_hidden_tests(exercise, &copy) != Some(false)
+    });
+    sandbox::remove_scratch_dir(&work_dir);
+    result
+}
+
+// 习题配置了内存上限或复杂度要求时，检查 src 处的代码是否满足
+fn meets_limits(exercise: &Exercise, src: &Path) -> io::Result<bool> {
+    let memory = exercise.max_peak_bytes.is_some() || exercise.memory_call.is_some();
+    if !memory && exercise.complexity.is_none() {
+        return Ok(true);
+    }
+    let work_dir = sandbox::create_scratch_dir(&format!("limits-{}", exercise.name))?;
+    let result = runtime::copy_project(exercise, src, &work_dir).map(|copy| {
+        let memory_ok = !memory || memory::evaluate_memory(exercise, &copy, None) != Some(false);
+        memory_ok && complexity::evaluate_complexity(exercise, &copy, None) != Some(false)
+    });
+    sandbox::remove_scratch_dir(&work_dir);
+    result
+}
+
+// 只检查代码能否编译（含测试代码），不运行测试
+fn compiles(exercise: &Exercise, src: &Path) -> io::Result<bool> {
+    let work_dir = sandbox::create_scratch_dir(&format!("check-{}", exercise.name))?;
+    let result = runtime::copy_project(exercise, src, &work_dir).and_then(|copy| {
+        let status = match exercise.exercise_type.as_str() {
+            "single_file" => Command::new("rustc")
+                .args(["--test", "--emit=metadata"])
+                .args(compile::rustc_args(exercise, false))
+                .envs(&exercise.env)
+                .arg("-o")
+                .arg(work_dir.join("check.rmeta"))
+                .arg(&copy)
+                .output()?
+                .status,
+            "function_cases" => {
+                let cases = cases::load_cases(exercise, &copy)?;
+                cases::build_harness(exercise, &copy, &cases, &work_dir, false, None)?.0.status
+            }
+            _ => {
+                let mut cargo = Command::new("cargo");
+                cargo.args(["check", "--all-targets"]).current_dir(&copy).envs(&exercise.env);
+                compile::apply_cargo_flags(&mut cargo, exercise, false);
+                cargo.output()?.status
+            }
+        };
+        Ok(status.success())
+    });
+    sandbox::remove_scratch_dir(&work_dir);
+    result
+}
+
+// 检查单道习题：参考答案必须通过，初始版本必须能编译但无法通过测试
+fn verify_exercise(exercise: &Exercise) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    match solution_path(exercise) {
+        Some(solution) if solution.exists() => {
+            match grade_copy(exercise, &solution, "solution") {
+                Ok(true) => match meets_limits(exercise, &solution) {
+                    Ok(true) => {}
+                    Ok(false) => problems.push(i18n::solution_exceeds_limits()),
+                    Err(e) => problems.push(i18n::solution_grade_failed(e)),
+                },
+                Ok(false) => problems.push(i18n::solution_fails()),
+                Err(e) => problems.push(i18n::solution_grade_failed(e)),
+            }
+            match constraints::check_constraints(exercise, &solution) {
+                Ok(violations) => problems.extend(violations.iter().map(i18n::solution_breaks_rule)),
+                Err(e) => problems.push(i18n::solution_rule_check_failed(e)),
+            }
+        }
+        Some(solution) => problems.push(i18n::solution_not_found(solution.display())),
+        None => println!("{}", i18n::no_solution_configured(&exercise.name)),
+    }
+
+    let stub = reset::pristine_path(exercise);
+    if stub.exists() {
+        match compiles(exercise, &stub) {
+            Ok(true) => match grade_copy(exercise, &stub, "stub") {
+                Ok(true) => problems.push(i18n::stub_passes()),
+                Ok(false) => {}
+                Err(e) => problems.push(i18n::stub_grade_failed(e)),
+            },
+            Ok(false) => problems.push(i18n::stub_does_not_compile()),
+            Err(e) => problems.push(i18n::stub_compile_failed(e)),
+        }
+    } else {
+        println!("{}", i18n::no_pristine_copy(&exercise.name));
+    }
+
+    problems
+}
+
+// 校验所有习题的参考答案与初始版本，返回是否全部符合预期
+pub fn verify_solutions(exercises: &[Exercise]) -> bool {
+    let mut flagged = Vec::new();
+    for exercise in exercises {
+        println!("\n{}", i18n::verifying(&exercise.name));
+        let problems = verify_exercise(exercise);
+        // 被中断时结果不完整，不输出汇总
+        if interrupt::is_interrupted() {
+            return false;
+        }
+        if problems.is_empty() {
+            println!("\x1b[32m{}\x1b[0m", i18n::verify_ok(&exercise.name));
+        } else {
+            for problem in &problems {
+                println!("\x1b[31m{}: {}\x1b[0m", exercise.name, problem);
+            }
+            flagged.push((exercise.name.clone(), problems));
+        }
+    }
+
+    println!("\n{}", i18n::verification_summary());
+    println!("{}", i18n::exercises_verified(exercises.len()));
+    println!("{}", i18n::exercises_flagged(flagged.len()));
+    for (name, problems) in &flagged {
+        println!("  {}: {}", name, problems.join("; "));
+    }
+    flagged.is_empty()
+}